@@ -0,0 +1,492 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_ec2::types::{Filter, NatGatewayState, PrefixListState, VpcAttributeName};
+use std::collections::HashMap;
+
+use crate::{
+    aws::{
+        ec2::model::{
+            DhcpOptions, ElasticIp, InternetGateway, ManagedPrefixList, NatGateway,
+            PrefixListEntry, RouteTable, SecurityGroup, SecurityGroupResource, SecurityGroupRule,
+            Subnet, Vpc, VpcEndpoint, VpcResource,
+        },
+        error::SdkResultExt,
+    },
+    core::{FetchError, Report, ResourceFetcher},
+    terraform::normalize_resource_name,
+};
+
+/// VPC endpoints in these states are gone or on their way out.
+const INACTIVE_ENDPOINT_STATES: &[&str] = &["deleting", "deleted", "rejected", "failed", "expired"];
+
+#[derive(Clone)]
+pub struct VpcFetcher {
+    client: aws_sdk_ec2::Client,
+    report: Report,
+}
+
+impl VpcFetcher {
+    pub fn new(config: aws_config::SdkConfig) -> Self {
+        Self {
+            client: aws_sdk_ec2::Client::new(&config),
+            report: Report::new(),
+        }
+    }
+
+    async fn fetch_vpcs(&self) -> Result<Vec<Vpc>> {
+        let vpcs = self
+            .client
+            .describe_vpcs()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(vpcs.iter().map(Vpc::from_aws_vpc).collect())
+    }
+
+    async fn fetch_subnets(&self) -> Result<Vec<Subnet>> {
+        let subnets = self
+            .client
+            .describe_subnets()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(subnets.iter().map(Subnet::from_aws_subnet).collect())
+    }
+
+    async fn fetch_route_tables(&self) -> Result<Vec<RouteTable>> {
+        let route_tables = self
+            .client
+            .describe_route_tables()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(route_tables
+            .iter()
+            .map(RouteTable::from_aws_route_table)
+            .collect())
+    }
+
+    async fn fetch_internet_gateways(&self) -> Result<Vec<InternetGateway>> {
+        let internet_gateways = self
+            .client
+            .describe_internet_gateways()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(internet_gateways
+            .iter()
+            .filter_map(InternetGateway::from_aws_internet_gateway)
+            .collect())
+    }
+
+    async fn fetch_nat_gateways(&self) -> Result<Vec<NatGateway>> {
+        let nat_gateways = self
+            .client
+            .describe_nat_gateways()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        // Deleted NAT gateways remain visible for about an hour.
+        Ok(nat_gateways
+            .iter()
+            .filter(|nat_gateway| {
+                matches!(
+                    nat_gateway.state(),
+                    Some(NatGatewayState::Available | NatGatewayState::Pending)
+                )
+            })
+            .map(NatGateway::from_aws_nat_gateway)
+            .collect())
+    }
+
+    async fn fetch_endpoints(&self) -> Result<Vec<VpcEndpoint>> {
+        let endpoints = self
+            .client
+            .describe_vpc_endpoints()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(endpoints
+            .iter()
+            .filter(|endpoint| {
+                !endpoint.state().is_some_and(|state| {
+                    INACTIVE_ENDPOINT_STATES.contains(&state.as_str().to_lowercase().as_str())
+                })
+            })
+            .map(VpcEndpoint::from_aws_endpoint)
+            .collect())
+    }
+
+    async fn fetch_elastic_ips(&self) -> Result<Vec<ElasticIp>> {
+        let output = self.client.describe_addresses().send().await?;
+
+        Ok(output
+            .addresses()
+            .iter()
+            .filter_map(ElasticIp::from_aws_address)
+            .collect())
+    }
+
+    async fn fetch_dhcp_options(&self) -> Result<Vec<DhcpOptions>> {
+        let dhcp_options = self
+            .client
+            .describe_dhcp_options()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(dhcp_options
+            .iter()
+            .map(DhcpOptions::from_aws_dhcp_options)
+            .collect())
+    }
+
+    async fn fetch_vpc_attribute(
+        &self,
+        vpc_id: &str,
+        attribute: VpcAttributeName,
+    ) -> Result<Option<bool>, FetchError> {
+        self.client
+            .describe_vpc_attribute()
+            .vpc_id(vpc_id)
+            .attribute(attribute.clone())
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| {
+                let value = match attribute {
+                    VpcAttributeName::EnableDnsHostnames => output.enable_dns_hostnames(),
+                    _ => output.enable_dns_support(),
+                };
+
+                value.and_then(|value| value.value())
+            })
+    }
+}
+
+#[async_trait]
+impl ResourceFetcher for VpcFetcher {
+    const SERVICE: &'static str = "ec2";
+
+    type Resource = VpcResource;
+
+    async fn fetch(&self) -> Result<Vec<VpcResource>> {
+        let (
+            mut vpcs,
+            mut subnets,
+            mut route_tables,
+            mut internet_gateways,
+            mut nat_gateways,
+            mut endpoints,
+            mut elastic_ips,
+            mut dhcp_options,
+        ) = tokio::try_join!(
+            self.fetch_vpcs(),
+            self.fetch_subnets(),
+            self.fetch_route_tables(),
+            self.fetch_internet_gateways(),
+            self.fetch_nat_gateways(),
+            self.fetch_endpoints(),
+            self.fetch_elastic_ips(),
+            self.fetch_dhcp_options(),
+        )?;
+
+        clear_shared_names(&mut vpcs, |vpc| &mut vpc.name);
+        clear_shared_names(&mut subnets, |subnet| &mut subnet.name);
+        clear_shared_names(&mut route_tables, |route_table| &mut route_table.name);
+        clear_shared_names(&mut internet_gateways, |internet_gateway| {
+            &mut internet_gateway.name
+        });
+        clear_shared_names(&mut nat_gateways, |nat_gateway| &mut nat_gateway.name);
+        clear_shared_names(&mut endpoints, |endpoint| &mut endpoint.name);
+        clear_shared_names(&mut elastic_ips, |elastic_ip| &mut elastic_ip.name);
+        clear_shared_names(&mut dhcp_options, |dhcp_options| &mut dhcp_options.name);
+
+        for vpc in &mut vpcs {
+            let (enable_dns_support, enable_dns_hostnames) = tokio::join!(
+                self.fetch_vpc_attribute(&vpc.id, VpcAttributeName::EnableDnsSupport),
+                self.fetch_vpc_attribute(&vpc.id, VpcAttributeName::EnableDnsHostnames),
+            );
+
+            vpc.enable_dns_support = self
+                .report
+                .check(&vpc.id, "enable dns support", enable_dns_support)
+                .flatten();
+            vpc.enable_dns_hostnames = self
+                .report
+                .check(&vpc.id, "enable dns hostnames", enable_dns_hostnames)
+                .flatten();
+        }
+
+        for subnet in subnets {
+            if let Some(vpc) = vpcs.iter_mut().find(|vpc| vpc.id == subnet.vpc_id) {
+                vpc.subnets.push(subnet);
+            }
+        }
+
+        for route_table in route_tables {
+            if let Some(vpc) = vpcs.iter_mut().find(|vpc| vpc.id == route_table.vpc_id) {
+                vpc.route_tables.push(route_table);
+            }
+        }
+
+        for internet_gateway in internet_gateways {
+            if let Some(vpc) = vpcs
+                .iter_mut()
+                .find(|vpc| vpc.id == internet_gateway.vpc_id)
+            {
+                vpc.internet_gateways.push(internet_gateway);
+            }
+        }
+
+        for nat_gateway in nat_gateways {
+            if let Some(vpc) = vpcs.iter_mut().find(|vpc| vpc.id == nat_gateway.vpc_id) {
+                vpc.nat_gateways.push(nat_gateway);
+            }
+        }
+
+        for endpoint in endpoints {
+            if let Some(vpc) = vpcs.iter_mut().find(|vpc| vpc.id == endpoint.vpc_id) {
+                vpc.endpoints.push(endpoint);
+            }
+        }
+
+        // Account-level resources come first, since VPCs and their NAT gateways refer to
+        // them.
+        let mut results: Vec<VpcResource> = Vec::new();
+        results.extend(dhcp_options.into_iter().map(VpcResource::DhcpOptions));
+        results.extend(elastic_ips.into_iter().map(VpcResource::ElasticIp));
+        results.extend(vpcs.into_iter().map(|vpc| VpcResource::Vpc(Box::new(vpc))));
+
+        Ok(results)
+    }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct SecurityGroupFetcher {
+    client: aws_sdk_ec2::Client,
+    sts_client: aws_sdk_sts::Client,
+    report: Report,
+}
+
+impl SecurityGroupFetcher {
+    pub fn new(config: aws_config::SdkConfig) -> Self {
+        Self {
+            client: aws_sdk_ec2::Client::new(&config),
+            sts_client: aws_sdk_sts::Client::new(&config),
+            report: Report::new(),
+        }
+    }
+
+    async fn fetch_security_groups(&self) -> Result<Vec<SecurityGroup>> {
+        let security_groups = self
+            .client
+            .describe_security_groups()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(security_groups
+            .iter()
+            .map(SecurityGroup::from_aws_security_group)
+            .collect())
+    }
+
+    async fn fetch_rules(&self) -> Result<Vec<SecurityGroupRule>> {
+        let rules = self
+            .client
+            .describe_security_group_rules()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        Ok(rules.iter().map(SecurityGroupRule::from_aws_rule).collect())
+    }
+
+    /// Fetches the prefix lists owned by the account. Lists managed by AWS, e.g. for S3
+    /// gateway endpoints, and those shared by other accounts are referred to by ID.
+    async fn fetch_prefix_lists(&self) -> Vec<ManagedPrefixList> {
+        let identity = self
+            .sts_client
+            .get_caller_identity()
+            .send()
+            .await
+            .or_fetch_error();
+
+        let Some(identity) = self.report.check("account", "caller identity", identity) else {
+            return Vec::new();
+        };
+
+        let account_id = identity.account().unwrap_or_default().to_string();
+
+        let prefix_lists = self
+            .client
+            .describe_managed_prefix_lists()
+            .filters(
+                Filter::builder()
+                    .name("owner-id")
+                    .values(&account_id)
+                    .build(),
+            )
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error();
+
+        let Some(prefix_lists) =
+            self.report
+                .check(&account_id, "managed prefix lists", prefix_lists)
+        else {
+            return Vec::new();
+        };
+
+        let mut results = Vec::new();
+
+        for prefix_list in prefix_lists.iter().filter(|prefix_list| {
+            !matches!(
+                prefix_list.state(),
+                Some(PrefixListState::DeleteInProgress | PrefixListState::DeleteComplete)
+            )
+        }) {
+            let mut prefix_list = ManagedPrefixList::from_aws_prefix_list(prefix_list);
+
+            let entries = self.fetch_prefix_list_entries(&prefix_list.id).await;
+
+            if let Some(entries) = self.report.check(&prefix_list.id, "entries", entries) {
+                prefix_list.entries = entries;
+                results.push(prefix_list);
+            }
+        }
+
+        results
+    }
+
+    async fn fetch_prefix_list_entries(
+        &self,
+        prefix_list_id: &str,
+    ) -> Result<Vec<PrefixListEntry>, FetchError> {
+        let entries = self
+            .client
+            .get_managed_prefix_list_entries()
+            .prefix_list_id(prefix_list_id)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(entries
+            .iter()
+            .map(|entry| PrefixListEntry {
+                cidr: entry.cidr().unwrap_or_default().to_string(),
+                description: entry
+                    .description()
+                    .filter(|description| !description.is_empty())
+                    .map(|description| description.to_string()),
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl ResourceFetcher for SecurityGroupFetcher {
+    const SERVICE: &'static str = "ec2";
+
+    type Resource = SecurityGroupResource;
+
+    async fn fetch(&self) -> Result<Vec<SecurityGroupResource>> {
+        let (security_groups, rules, prefix_lists) = tokio::join!(
+            self.fetch_security_groups(),
+            self.fetch_rules(),
+            self.fetch_prefix_lists(),
+        );
+
+        let mut security_groups = security_groups?;
+
+        clear_shared_names(&mut security_groups, |security_group| {
+            &mut security_group.name
+        });
+
+        for rule in rules? {
+            if let Some(security_group) = security_groups
+                .iter_mut()
+                .find(|security_group| security_group.id == rule.group_id)
+            {
+                security_group.rules.push(rule);
+            }
+        }
+
+        // Prefix lists come first, since security group rules refer to them.
+        let mut results: Vec<SecurityGroupResource> = Vec::new();
+        results.extend(
+            prefix_lists
+                .into_iter()
+                .map(SecurityGroupResource::ManagedPrefixList),
+        );
+        results.extend(
+            security_groups
+                .into_iter()
+                .map(SecurityGroupResource::SecurityGroup),
+        );
+
+        Ok(results)
+    }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
+
+/// `Name` tags need not be unique, so resources whose name is shared with another of
+/// the same kind are named after their ID instead.
+fn clear_shared_names<T>(items: &mut [T], name: impl Fn(&mut T) -> &mut Option<String>) {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for item in items.iter_mut() {
+        if let Some(value) = name(item) {
+            *counts.entry(normalize_resource_name(value)).or_default() += 1;
+        }
+    }
+
+    for item in items.iter_mut() {
+        let value = name(item);
+
+        if value
+            .as_ref()
+            .is_some_and(|value| counts[&normalize_resource_name(value)] > 1)
+        {
+            *value = None;
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod fetcher;
+mod model;
+mod resources;
+
+pub use fetcher::{SecurityGroupFetcher, VpcFetcher};
+pub use model::*;
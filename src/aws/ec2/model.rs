@@ -0,0 +1,553 @@
+/// Everything fetched for VPC networking: VPCs along with the account-level resources
+/// they use.
+pub enum VpcResource {
+    Vpc(Box<Vpc>),
+    ElasticIp(ElasticIp),
+    DhcpOptions(DhcpOptions),
+}
+
+pub struct Vpc {
+    pub id: String,
+    /// The value of the `Name` tag, if it is unique among VPCs.
+    pub name: Option<String>,
+    pub cidr_block: String,
+    pub instance_tenancy: String,
+    pub is_default: bool,
+    pub enable_dns_support: Option<bool>,
+    pub enable_dns_hostnames: Option<bool>,
+    pub assign_generated_ipv6_cidr_block: bool,
+    pub dhcp_options_id: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub subnets: Vec<Subnet>,
+    pub route_tables: Vec<RouteTable>,
+    pub internet_gateways: Vec<InternetGateway>,
+    pub nat_gateways: Vec<NatGateway>,
+    pub endpoints: Vec<VpcEndpoint>,
+}
+
+impl Vpc {
+    pub fn from_aws_vpc(vpc: &aws_sdk_ec2::types::Vpc) -> Self {
+        Self {
+            id: vpc.vpc_id().unwrap_or_default().to_string(),
+            name: name_tag(vpc.tags()),
+            cidr_block: vpc.cidr_block().unwrap_or_default().to_string(),
+            instance_tenancy: vpc
+                .instance_tenancy()
+                .map(|tenancy| tenancy.as_str().to_string())
+                .unwrap_or_default(),
+            is_default: vpc.is_default().unwrap_or(false),
+            enable_dns_support: None,
+            enable_dns_hostnames: None,
+            // Amazon-provided IPv6 blocks are allocated from the `Amazon` pool.
+            assign_generated_ipv6_cidr_block: vpc
+                .ipv6_cidr_block_association_set()
+                .iter()
+                .any(|association| association.ipv6_pool() == Some("Amazon")),
+            dhcp_options_id: vpc
+                .dhcp_options_id()
+                .filter(|id| *id != "default")
+                .map(|id| id.to_string()),
+            tags: tag_pairs(vpc.tags()),
+            subnets: Vec::new(),
+            route_tables: Vec::new(),
+            internet_gateways: Vec::new(),
+            nat_gateways: Vec::new(),
+            endpoints: Vec::new(),
+        }
+    }
+}
+
+pub struct Subnet {
+    pub id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub cidr_block: String,
+    pub ipv6_cidr_block: Option<String>,
+    pub availability_zone: String,
+    pub map_public_ip_on_launch: bool,
+    pub default_for_az: bool,
+    pub tags: Vec<(String, String)>,
+}
+
+impl Subnet {
+    pub fn from_aws_subnet(subnet: &aws_sdk_ec2::types::Subnet) -> Self {
+        Self {
+            id: subnet.subnet_id().unwrap_or_default().to_string(),
+            name: name_tag(subnet.tags()),
+            vpc_id: subnet.vpc_id().unwrap_or_default().to_string(),
+            cidr_block: subnet.cidr_block().unwrap_or_default().to_string(),
+            ipv6_cidr_block: subnet
+                .ipv6_cidr_block_association_set()
+                .first()
+                .and_then(|association| association.ipv6_cidr_block())
+                .map(|block| block.to_string()),
+            availability_zone: subnet.availability_zone().unwrap_or_default().to_string(),
+            map_public_ip_on_launch: subnet.map_public_ip_on_launch().unwrap_or(false),
+            default_for_az: subnet.default_for_az().unwrap_or(false),
+            tags: tag_pairs(subnet.tags()),
+        }
+    }
+}
+
+pub struct RouteTable {
+    pub id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub routes: Vec<Route>,
+    pub associations: Vec<RouteTableAssociation>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl RouteTable {
+    pub fn from_aws_route_table(route_table: &aws_sdk_ec2::types::RouteTable) -> Self {
+        Self {
+            id: route_table.route_table_id().unwrap_or_default().to_string(),
+            name: name_tag(route_table.tags()),
+            vpc_id: route_table.vpc_id().unwrap_or_default().to_string(),
+            routes: route_table
+                .routes()
+                .iter()
+                .filter_map(Route::from_aws_route)
+                .collect(),
+            associations: route_table
+                .associations()
+                .iter()
+                .filter_map(RouteTableAssociation::from_aws_association)
+                .collect(),
+            tags: tag_pairs(route_table.tags()),
+        }
+    }
+}
+
+pub struct Route {
+    pub destination: RouteDestination,
+    pub target: RouteTarget,
+}
+
+impl Route {
+    /// Returns `None` for routes that are managed by something else, such as the local
+    /// route every table starts with, routes propagated from a virtual private gateway
+    /// and the routes of gateway VPC endpoints.
+    pub fn from_aws_route(route: &aws_sdk_ec2::types::Route) -> Option<Self> {
+        if route.origin() != Some(&aws_sdk_ec2::types::RouteOrigin::CreateRoute) {
+            return None;
+        }
+
+        let destination = if let Some(cidr_block) = route.destination_cidr_block() {
+            RouteDestination::CidrBlock(cidr_block.to_string())
+        } else if let Some(cidr_block) = route.destination_ipv6_cidr_block() {
+            RouteDestination::Ipv6CidrBlock(cidr_block.to_string())
+        } else {
+            RouteDestination::PrefixList(route.destination_prefix_list_id()?.to_string())
+        };
+
+        let target = if let Some(id) = route.nat_gateway_id() {
+            RouteTarget::NatGateway(id.to_string())
+        } else if let Some(id) = route.transit_gateway_id() {
+            RouteTarget::TransitGateway(id.to_string())
+        } else if let Some(id) = route.vpc_peering_connection_id() {
+            RouteTarget::VpcPeeringConnection(id.to_string())
+        } else if let Some(id) = route.egress_only_internet_gateway_id() {
+            RouteTarget::EgressOnlyInternetGateway(id.to_string())
+        } else if let Some(id) = route.carrier_gateway_id() {
+            RouteTarget::CarrierGateway(id.to_string())
+        } else if let Some(id) = route.local_gateway_id() {
+            RouteTarget::LocalGateway(id.to_string())
+        } else if let Some(arn) = route.core_network_arn() {
+            RouteTarget::CoreNetwork(arn.to_string())
+        } else if let Some(id) = route.network_interface_id() {
+            RouteTarget::NetworkInterface(id.to_string())
+        } else {
+            let id = route.gateway_id()?;
+
+            if id.starts_with("vpce-") {
+                return None;
+            }
+
+            RouteTarget::Gateway(id.to_string())
+        };
+
+        Some(Self {
+            destination,
+            target,
+        })
+    }
+}
+
+pub enum RouteDestination {
+    CidrBlock(String),
+    Ipv6CidrBlock(String),
+    PrefixList(String),
+}
+
+impl RouteDestination {
+    pub fn value(&self) -> &str {
+        match self {
+            RouteDestination::CidrBlock(value)
+            | RouteDestination::Ipv6CidrBlock(value)
+            | RouteDestination::PrefixList(value) => value,
+        }
+    }
+}
+
+pub enum RouteTarget {
+    /// An internet gateway or virtual private gateway.
+    Gateway(String),
+    NatGateway(String),
+    TransitGateway(String),
+    VpcPeeringConnection(String),
+    EgressOnlyInternetGateway(String),
+    CarrierGateway(String),
+    LocalGateway(String),
+    CoreNetwork(String),
+    NetworkInterface(String),
+}
+
+/// What a route table is associated with, besides the VPC it is the main table of.
+pub enum RouteTableAssociation {
+    Subnet(String),
+    Gateway(String),
+}
+
+impl RouteTableAssociation {
+    /// Returns `None` for the main route table's implicit association with its VPC.
+    pub fn from_aws_association(
+        association: &aws_sdk_ec2::types::RouteTableAssociation,
+    ) -> Option<Self> {
+        if association.main() == Some(true) {
+            return None;
+        }
+
+        match (association.subnet_id(), association.gateway_id()) {
+            (Some(subnet_id), _) => Some(RouteTableAssociation::Subnet(subnet_id.to_string())),
+            (None, Some(gateway_id)) => {
+                Some(RouteTableAssociation::Gateway(gateway_id.to_string()))
+            }
+            (None, None) => None,
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        match self {
+            RouteTableAssociation::Subnet(id) | RouteTableAssociation::Gateway(id) => id,
+        }
+    }
+}
+
+pub struct InternetGateway {
+    pub id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl InternetGateway {
+    /// Returns `None` for gateways that are not attached to a VPC.
+    pub fn from_aws_internet_gateway(
+        internet_gateway: &aws_sdk_ec2::types::InternetGateway,
+    ) -> Option<Self> {
+        let vpc_id = internet_gateway.attachments().first()?.vpc_id()?;
+
+        Some(Self {
+            id: internet_gateway
+                .internet_gateway_id()
+                .unwrap_or_default()
+                .to_string(),
+            name: name_tag(internet_gateway.tags()),
+            vpc_id: vpc_id.to_string(),
+            tags: tag_pairs(internet_gateway.tags()),
+        })
+    }
+}
+
+pub struct NatGateway {
+    pub id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub subnet_id: String,
+    pub connectivity_type: String,
+    pub allocation_id: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl NatGateway {
+    pub fn from_aws_nat_gateway(nat_gateway: &aws_sdk_ec2::types::NatGateway) -> Self {
+        Self {
+            id: nat_gateway.nat_gateway_id().unwrap_or_default().to_string(),
+            name: name_tag(nat_gateway.tags()),
+            vpc_id: nat_gateway.vpc_id().unwrap_or_default().to_string(),
+            subnet_id: nat_gateway.subnet_id().unwrap_or_default().to_string(),
+            connectivity_type: nat_gateway
+                .connectivity_type()
+                .map(|connectivity_type| connectivity_type.as_str().to_string())
+                .unwrap_or_default(),
+            allocation_id: nat_gateway
+                .nat_gateway_addresses()
+                .iter()
+                .find(|address| address.is_primary() != Some(false))
+                .and_then(|address| address.allocation_id())
+                .map(|id| id.to_string()),
+            tags: tag_pairs(nat_gateway.tags()),
+        }
+    }
+}
+
+pub struct VpcEndpoint {
+    pub id: String,
+    pub name: Option<String>,
+    pub vpc_id: String,
+    pub service_name: String,
+    pub endpoint_type: String,
+    pub policy: Option<String>,
+    pub private_dns_enabled: bool,
+    pub route_table_ids: Vec<String>,
+    pub subnet_ids: Vec<String>,
+    pub security_group_ids: Vec<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl VpcEndpoint {
+    pub fn from_aws_endpoint(endpoint: &aws_sdk_ec2::types::VpcEndpoint) -> Self {
+        Self {
+            id: endpoint.vpc_endpoint_id().unwrap_or_default().to_string(),
+            name: name_tag(endpoint.tags()),
+            vpc_id: endpoint.vpc_id().unwrap_or_default().to_string(),
+            service_name: endpoint.service_name().unwrap_or_default().to_string(),
+            endpoint_type: endpoint
+                .vpc_endpoint_type()
+                .map(|endpoint_type| endpoint_type.as_str().to_string())
+                .unwrap_or_default(),
+            policy: endpoint.policy_document().map(|policy| policy.to_string()),
+            private_dns_enabled: endpoint.private_dns_enabled().unwrap_or(false),
+            route_table_ids: endpoint
+                .route_table_ids()
+                .iter()
+                .map(|id| id.to_string())
+                .collect(),
+            subnet_ids: endpoint
+                .subnet_ids()
+                .iter()
+                .map(|id| id.to_string())
+                .collect(),
+            security_group_ids: endpoint
+                .groups()
+                .iter()
+                .filter_map(|group| group.group_id())
+                .map(|id| id.to_string())
+                .collect(),
+            tags: tag_pairs(endpoint.tags()),
+        }
+    }
+}
+
+pub struct ElasticIp {
+    pub allocation_id: String,
+    pub name: Option<String>,
+    pub public_ipv4_pool: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl ElasticIp {
+    /// Returns `None` for addresses allocated for use in EC2-Classic, which have no
+    /// allocation ID.
+    pub fn from_aws_address(address: &aws_sdk_ec2::types::Address) -> Option<Self> {
+        Some(Self {
+            allocation_id: address.allocation_id()?.to_string(),
+            name: name_tag(address.tags()),
+            public_ipv4_pool: address
+                .public_ipv4_pool()
+                .filter(|pool| *pool != "amazon")
+                .map(|pool| pool.to_string()),
+            tags: tag_pairs(address.tags()),
+        })
+    }
+}
+
+pub struct DhcpOptions {
+    pub id: String,
+    pub name: Option<String>,
+    pub domain_name: Option<String>,
+    pub domain_name_servers: Vec<String>,
+    pub ntp_servers: Vec<String>,
+    pub netbios_name_servers: Vec<String>,
+    pub netbios_node_type: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl DhcpOptions {
+    pub fn from_aws_dhcp_options(dhcp_options: &aws_sdk_ec2::types::DhcpOptions) -> Self {
+        let values = |key: &str| -> Vec<String> {
+            dhcp_options
+                .dhcp_configurations()
+                .iter()
+                .filter(|configuration| configuration.key() == Some(key))
+                .flat_map(|configuration| configuration.values())
+                .filter_map(|value| value.value())
+                .map(|value| value.to_string())
+                .collect()
+        };
+
+        Self {
+            id: dhcp_options
+                .dhcp_options_id()
+                .unwrap_or_default()
+                .to_string(),
+            name: name_tag(dhcp_options.tags()),
+            domain_name: values("domain-name").into_iter().next(),
+            domain_name_servers: values("domain-name-servers"),
+            ntp_servers: values("ntp-servers"),
+            netbios_name_servers: values("netbios-name-servers"),
+            netbios_node_type: values("netbios-node-type").into_iter().next(),
+            tags: tag_pairs(dhcp_options.tags()),
+        }
+    }
+}
+
+/// Everything fetched for security groups: the groups along with the customer-managed
+/// prefix lists their rules may refer to.
+pub enum SecurityGroupResource {
+    SecurityGroup(SecurityGroup),
+    ManagedPrefixList(ManagedPrefixList),
+}
+
+pub struct SecurityGroup {
+    pub id: String,
+    /// The group name, if it is unique among security groups. Every VPC has a group
+    /// named `default`, for one.
+    pub name: Option<String>,
+    pub group_name: String,
+    pub description: String,
+    pub vpc_id: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub rules: Vec<SecurityGroupRule>,
+}
+
+impl SecurityGroup {
+    pub fn from_aws_security_group(security_group: &aws_sdk_ec2::types::SecurityGroup) -> Self {
+        let group_name = security_group.group_name().unwrap_or_default().to_string();
+
+        Self {
+            id: security_group.group_id().unwrap_or_default().to_string(),
+            name: Some(group_name.clone()),
+            group_name,
+            description: security_group.description().unwrap_or_default().to_string(),
+            vpc_id: security_group.vpc_id().map(|id| id.to_string()),
+            tags: tag_pairs(security_group.tags()),
+            rules: Vec::new(),
+        }
+    }
+
+    /// Every VPC comes with a default security group, which cannot be deleted.
+    pub fn is_default(&self) -> bool {
+        self.group_name == "default"
+    }
+}
+
+pub struct SecurityGroupRule {
+    pub id: String,
+    pub group_id: String,
+    pub is_egress: bool,
+    pub ip_protocol: String,
+    pub from_port: Option<i32>,
+    pub to_port: Option<i32>,
+    pub peer: Option<SecurityGroupRulePeer>,
+    pub description: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SecurityGroupRule {
+    pub fn from_aws_rule(rule: &aws_sdk_ec2::types::SecurityGroupRule) -> Self {
+        let peer = if let Some(cidr_block) = rule.cidr_ipv4() {
+            Some(SecurityGroupRulePeer::CidrIpv4(cidr_block.to_string()))
+        } else if let Some(cidr_block) = rule.cidr_ipv6() {
+            Some(SecurityGroupRulePeer::CidrIpv6(cidr_block.to_string()))
+        } else if let Some(prefix_list_id) = rule.prefix_list_id() {
+            Some(SecurityGroupRulePeer::PrefixList(
+                prefix_list_id.to_string(),
+            ))
+        } else {
+            rule.referenced_group_info().and_then(|group| {
+                let group_id = group.group_id()?.to_string();
+
+                // Groups in a peered VPC of another account are qualified with the
+                // account that owns them.
+                Some(match group.user_id() {
+                    Some(user_id) if Some(user_id) != rule.group_owner_id() => {
+                        SecurityGroupRulePeer::SecurityGroup(format!("{}/{}", user_id, group_id))
+                    }
+                    _ => SecurityGroupRulePeer::SecurityGroup(group_id),
+                })
+            })
+        };
+
+        Self {
+            id: rule
+                .security_group_rule_id()
+                .unwrap_or_default()
+                .to_string(),
+            group_id: rule.group_id().unwrap_or_default().to_string(),
+            is_egress: rule.is_egress().unwrap_or(false),
+            ip_protocol: rule.ip_protocol().unwrap_or("-1").to_string(),
+            from_port: rule.from_port(),
+            to_port: rule.to_port(),
+            peer,
+            description: rule
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(|description| description.to_string()),
+            tags: tag_pairs(rule.tags()),
+        }
+    }
+}
+
+/// The traffic source of an ingress rule, or destination of an egress rule.
+pub enum SecurityGroupRulePeer {
+    CidrIpv4(String),
+    CidrIpv6(String),
+    PrefixList(String),
+    SecurityGroup(String),
+}
+
+pub struct ManagedPrefixList {
+    pub id: String,
+    pub name: String,
+    pub address_family: String,
+    pub max_entries: Option<i32>,
+    pub entries: Vec<PrefixListEntry>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl ManagedPrefixList {
+    pub fn from_aws_prefix_list(prefix_list: &aws_sdk_ec2::types::ManagedPrefixList) -> Self {
+        Self {
+            id: prefix_list.prefix_list_id().unwrap_or_default().to_string(),
+            name: prefix_list
+                .prefix_list_name()
+                .unwrap_or_default()
+                .to_string(),
+            address_family: prefix_list.address_family().unwrap_or_default().to_string(),
+            max_entries: prefix_list.max_entries(),
+            entries: Vec::new(),
+            tags: tag_pairs(prefix_list.tags()),
+        }
+    }
+}
+
+pub struct PrefixListEntry {
+    pub cidr: String,
+    pub description: Option<String>,
+}
+
+fn name_tag(tags: &[aws_sdk_ec2::types::Tag]) -> Option<String> {
+    tags.iter()
+        .find(|tag| tag.key() == Some("Name"))
+        .and_then(|tag| tag.value())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+}
+
+fn tag_pairs(tags: &[aws_sdk_ec2::types::Tag]) -> Vec<(String, String)> {
+    tags.iter()
+        .filter_map(|tag| Some((tag.key()?.to_string(), tag.value()?.to_string())))
+        .collect()
+}
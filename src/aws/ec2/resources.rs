@@ -0,0 +1,535 @@
+use std::collections::HashMap;
+
+use crate::{
+    aws::ec2::{
+        DhcpOptions, ElasticIp, InternetGateway, ManagedPrefixList, NatGateway, Route,
+        RouteDestination, RouteTable, RouteTableAssociation, RouteTarget, SecurityGroup,
+        SecurityGroupResource, SecurityGroupRule, SecurityGroupRulePeer, Subnet, Vpc, VpcEndpoint,
+        VpcResource,
+    },
+    core::{
+        IntoResources,
+        ir::{Attributes, Lookup, Reference, Resource, Value},
+    },
+    terraform::normalize_resource_name,
+};
+
+const SUBNET_TYPES: &[&str] = &["aws_subnet", "aws_default_subnet"];
+const VPC_TYPES: &[&str] = &["aws_vpc", "aws_default_vpc"];
+const SECURITY_GROUP_TYPES: &[&str] = &["aws_security_group", "aws_default_security_group"];
+
+impl IntoResources for VpcResource {
+    fn into_resources(self) -> Vec<Resource> {
+        match self {
+            VpcResource::Vpc(vpc) => vpc.into_resources(),
+            VpcResource::ElasticIp(elastic_ip) => elastic_ip.into_resources(),
+            VpcResource::DhcpOptions(dhcp_options) => dhcp_options.into_resources(),
+        }
+    }
+}
+
+impl IntoResources for Vpc {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = resource_name(&self.id, self.name.as_deref());
+
+        // The default VPC is adopted rather than managed, so that destroying the
+        // configuration does not delete it.
+        let mut vpc = if self.is_default {
+            Resource::new("aws_default_vpc", &resource_name, &self.id)
+        } else {
+            let mut vpc = Resource::new("aws_vpc", &resource_name, &self.id)
+                .with("cidr_block", &self.cidr_block);
+
+            if self.instance_tenancy != "default" {
+                vpc = vpc.with("instance_tenancy", &self.instance_tenancy);
+            }
+
+            vpc
+        };
+
+        vpc = vpc
+            .with_identifier("id", &self.id)
+            .with_optional("enable_dns_support", self.enable_dns_support)
+            .with_optional("enable_dns_hostnames", self.enable_dns_hostnames);
+
+        if self.assign_generated_ipv6_cidr_block {
+            vpc = vpc.with("assign_generated_ipv6_cidr_block", true);
+        }
+
+        let vpc = vpc.with_optional("tags", tags(self.tags));
+        let vpc_reference = vpc.reference("id", &self.id);
+
+        // Associations are named after what they associate a route table with, which
+        // is unique.
+        let association_names: HashMap<String, String> = self
+            .subnets
+            .iter()
+            .map(|subnet| (subnet.id.clone(), subnet.name.clone()))
+            .chain(
+                self.internet_gateways
+                    .iter()
+                    .map(|gateway| (gateway.id.clone(), gateway.name.clone())),
+            )
+            .map(|(id, name)| {
+                let name = resource_name(&id, name.as_deref());
+                (id, name)
+            })
+            .collect();
+
+        let mut resources = vec![vpc];
+
+        if let Some(dhcp_options_id) = self.dhcp_options_id {
+            resources.push(
+                Resource::new("aws_vpc_dhcp_options_association", &resource_name, &self.id)
+                    .with("vpc_id", vpc_reference.clone())
+                    .with(
+                        "dhcp_options_id",
+                        Lookup::new(&["aws_vpc_dhcp_options"], dhcp_options_id),
+                    ),
+            );
+        }
+
+        for subnet in self.subnets {
+            resources.push(subnet_resource(subnet, &vpc_reference));
+        }
+
+        for internet_gateway in self.internet_gateways {
+            resources.push(internet_gateway_resource(internet_gateway, &vpc_reference));
+        }
+
+        for nat_gateway in self.nat_gateways {
+            resources.push(nat_gateway_resource(nat_gateway));
+        }
+
+        for route_table in self.route_tables {
+            resources.extend(route_table_resources(
+                route_table,
+                &vpc_reference,
+                &association_names,
+            ));
+        }
+
+        for endpoint in self.endpoints {
+            resources.push(endpoint_resource(endpoint, &vpc_reference));
+        }
+
+        // Everything in the VPC is written alongside it.
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+impl IntoResources for ElasticIp {
+    fn into_resources(self) -> Vec<Resource> {
+        vec![
+            Resource::new(
+                "aws_eip",
+                resource_name(&self.allocation_id, self.name.as_deref()),
+                &self.allocation_id,
+            )
+            .with_identifier("id", &self.allocation_id)
+            .with("domain", "vpc")
+            .with_optional("public_ipv4_pool", self.public_ipv4_pool)
+            .with_optional("tags", tags(self.tags)),
+        ]
+    }
+}
+
+impl IntoResources for DhcpOptions {
+    fn into_resources(self) -> Vec<Resource> {
+        let mut dhcp_options = Resource::new(
+            "aws_vpc_dhcp_options",
+            resource_name(&self.id, self.name.as_deref()),
+            &self.id,
+        )
+        .with_identifier("id", &self.id)
+        .with_optional("domain_name", self.domain_name);
+
+        for (key, values) in [
+            ("domain_name_servers", self.domain_name_servers),
+            ("ntp_servers", self.ntp_servers),
+            ("netbios_name_servers", self.netbios_name_servers),
+        ] {
+            if !values.is_empty() {
+                dhcp_options = dhcp_options.with(key, values);
+            }
+        }
+
+        vec![
+            dhcp_options
+                .with_optional("netbios_node_type", self.netbios_node_type)
+                .with_optional("tags", tags(self.tags)),
+        ]
+    }
+}
+
+impl IntoResources for SecurityGroupResource {
+    fn into_resources(self) -> Vec<Resource> {
+        match self {
+            SecurityGroupResource::SecurityGroup(security_group) => security_group.into_resources(),
+            SecurityGroupResource::ManagedPrefixList(prefix_list) => prefix_list.into_resources(),
+        }
+    }
+}
+
+impl IntoResources for SecurityGroup {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = resource_name(&self.id, self.name.as_deref());
+
+        // Like the default VPC, its default security group is adopted rather than
+        // managed. Neither resource declares inline rules: every rule is a standalone
+        // resource instead, which keeps rules that refer to each other's groups free of
+        // dependency cycles.
+        let security_group = if self.is_default() {
+            Resource::new("aws_default_security_group", &resource_name, &self.id)
+        } else {
+            Resource::new("aws_security_group", &resource_name, &self.id)
+                .with("name", &self.group_name)
+                .with("description", &self.description)
+        };
+
+        let security_group = security_group
+            .with_identifier("id", &self.id)
+            .with_optional(
+                "vpc_id",
+                self.vpc_id.map(|vpc_id| Lookup::new(VPC_TYPES, vpc_id)),
+            )
+            .with_optional("tags", tags(self.tags));
+        let security_group_reference = security_group.reference("id", &self.id);
+
+        let mut resources = vec![security_group];
+
+        for rule in self.rules {
+            resources.push(security_group_rule_resource(
+                rule,
+                &resource_name,
+                &security_group_reference,
+            ));
+        }
+
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+impl IntoResources for ManagedPrefixList {
+    fn into_resources(self) -> Vec<Resource> {
+        let entries: Vec<_> = self
+            .entries
+            .into_iter()
+            .map(|entry| {
+                Attributes::new()
+                    .with("cidr", entry.cidr)
+                    .with_optional("description", entry.description)
+            })
+            .collect();
+
+        let mut prefix_list = Resource::new(
+            "aws_ec2_managed_prefix_list",
+            normalize_resource_name(&self.name),
+            &self.id,
+        )
+        .with_identifier("id", &self.id)
+        .with("name", self.name)
+        .with("address_family", self.address_family)
+        .with_optional("max_entries", self.max_entries);
+
+        if !entries.is_empty() {
+            prefix_list = prefix_list.with("entry", Value::Blocks(entries));
+        }
+
+        vec![prefix_list.with_optional("tags", tags(self.tags))]
+    }
+}
+
+fn security_group_rule_resource(
+    rule: SecurityGroupRule,
+    security_group_name: &str,
+    security_group_reference: &Reference,
+) -> Resource {
+    let resource_type = if rule.is_egress {
+        "aws_vpc_security_group_egress_rule"
+    } else {
+        "aws_vpc_security_group_ingress_rule"
+    };
+
+    let mut resource = Resource::new(
+        resource_type,
+        format!(
+            "{}_{}",
+            security_group_name,
+            normalize_resource_name(&rule.id)
+        ),
+        &rule.id,
+    )
+    .with("security_group_id", security_group_reference.clone())
+    .with("ip_protocol", &rule.ip_protocol);
+
+    // Rules for all protocols cover every port, and must not set any.
+    if rule.ip_protocol != "-1" {
+        resource = resource
+            .with_optional("from_port", rule.from_port)
+            .with_optional("to_port", rule.to_port);
+    }
+
+    resource = match rule.peer {
+        Some(SecurityGroupRulePeer::CidrIpv4(cidr_block)) => resource.with("cidr_ipv4", cidr_block),
+        Some(SecurityGroupRulePeer::CidrIpv6(cidr_block)) => resource.with("cidr_ipv6", cidr_block),
+        Some(SecurityGroupRulePeer::PrefixList(prefix_list_id)) => resource.with(
+            "prefix_list_id",
+            Lookup::new(&["aws_ec2_managed_prefix_list"], prefix_list_id),
+        ),
+        Some(SecurityGroupRulePeer::SecurityGroup(group_id)) => resource.with(
+            "referenced_security_group_id",
+            Lookup::new(SECURITY_GROUP_TYPES, group_id),
+        ),
+        None => resource,
+    };
+
+    resource
+        .with_optional("description", rule.description)
+        .with_optional("tags", tags(rule.tags))
+}
+
+fn subnet_resource(subnet: Subnet, vpc_reference: &Reference) -> Resource {
+    let resource_name = resource_name(&subnet.id, subnet.name.as_deref());
+
+    // Like the default VPC, its default subnets are adopted rather than managed.
+    let resource = if subnet.default_for_az {
+        Resource::new("aws_default_subnet", resource_name, &subnet.id)
+            .with("availability_zone", subnet.availability_zone)
+            .with("map_public_ip_on_launch", subnet.map_public_ip_on_launch)
+    } else {
+        let mut resource = Resource::new("aws_subnet", resource_name, &subnet.id)
+            .with("vpc_id", vpc_reference.clone())
+            .with("cidr_block", subnet.cidr_block)
+            .with_optional("ipv6_cidr_block", subnet.ipv6_cidr_block)
+            .with("availability_zone", subnet.availability_zone);
+
+        if subnet.map_public_ip_on_launch {
+            resource = resource.with("map_public_ip_on_launch", true);
+        }
+
+        resource
+    };
+
+    resource
+        .with_identifier("id", &subnet.id)
+        .with_optional("tags", tags(subnet.tags))
+}
+
+fn internet_gateway_resource(
+    internet_gateway: InternetGateway,
+    vpc_reference: &Reference,
+) -> Resource {
+    Resource::new(
+        "aws_internet_gateway",
+        resource_name(&internet_gateway.id, internet_gateway.name.as_deref()),
+        &internet_gateway.id,
+    )
+    .with_identifier("id", &internet_gateway.id)
+    .with("vpc_id", vpc_reference.clone())
+    .with_optional("tags", tags(internet_gateway.tags))
+}
+
+fn nat_gateway_resource(nat_gateway: NatGateway) -> Resource {
+    let mut resource = Resource::new(
+        "aws_nat_gateway",
+        resource_name(&nat_gateway.id, nat_gateway.name.as_deref()),
+        &nat_gateway.id,
+    )
+    .with_identifier("id", &nat_gateway.id)
+    .with(
+        "subnet_id",
+        Lookup::new(SUBNET_TYPES, nat_gateway.subnet_id),
+    )
+    .with_optional(
+        "allocation_id",
+        nat_gateway
+            .allocation_id
+            .map(|allocation_id| Lookup::new(&["aws_eip"], allocation_id)),
+    );
+
+    if nat_gateway.connectivity_type == "private" {
+        resource = resource.with("connectivity_type", nat_gateway.connectivity_type);
+    }
+
+    resource.with_optional("tags", tags(nat_gateway.tags))
+}
+
+fn route_table_resources(
+    route_table: RouteTable,
+    vpc_reference: &Reference,
+    association_names: &HashMap<String, String>,
+) -> Vec<Resource> {
+    let resource_name = resource_name(&route_table.id, route_table.name.as_deref());
+
+    // Routes and associations are separate resources, since they cannot be mixed with
+    // the inline `route` blocks of `aws_route_table`.
+    let resource = Resource::new("aws_route_table", &resource_name, &route_table.id)
+        .with_identifier("id", &route_table.id)
+        .with("vpc_id", vpc_reference.clone())
+        .with_optional("tags", tags(route_table.tags));
+    let route_table_reference = resource.reference("id", &route_table.id);
+
+    let mut resources = vec![resource];
+
+    for route in route_table.routes {
+        resources.push(route_resource(
+            route,
+            &resource_name,
+            &route_table.id,
+            &route_table_reference,
+        ));
+    }
+
+    for association in route_table.associations {
+        let target_name = association_names
+            .get(association.id())
+            .cloned()
+            .unwrap_or_else(|| normalize_resource_name(association.id()));
+
+        let resource = Resource::new(
+            "aws_route_table_association",
+            target_name,
+            format!("{}/{}", association.id(), route_table.id),
+        )
+        .with("route_table_id", route_table_reference.clone());
+
+        resources.push(match association {
+            RouteTableAssociation::Subnet(subnet_id) => {
+                resource.with("subnet_id", Lookup::new(SUBNET_TYPES, subnet_id))
+            }
+            RouteTableAssociation::Gateway(gateway_id) => resource.with(
+                "gateway_id",
+                Lookup::new(&["aws_internet_gateway"], gateway_id),
+            ),
+        });
+    }
+
+    resources
+}
+
+fn route_resource(
+    route: Route,
+    route_table_name: &str,
+    route_table_id: &str,
+    route_table_reference: &Reference,
+) -> Resource {
+    let destination = route.destination.value().to_string();
+
+    let resource = Resource::new(
+        "aws_route",
+        format!(
+            "{}_{}",
+            route_table_name,
+            normalize_resource_name(&destination)
+        ),
+        format!("{}_{}", route_table_id, destination),
+    )
+    .with("route_table_id", route_table_reference.clone());
+
+    let resource = match route.destination {
+        RouteDestination::CidrBlock(cidr_block) => {
+            resource.with("destination_cidr_block", cidr_block)
+        }
+        RouteDestination::Ipv6CidrBlock(cidr_block) => {
+            resource.with("destination_ipv6_cidr_block", cidr_block)
+        }
+        RouteDestination::PrefixList(prefix_list_id) => {
+            resource.with("destination_prefix_list_id", prefix_list_id)
+        }
+    };
+
+    match route.target {
+        RouteTarget::Gateway(id) => {
+            resource.with("gateway_id", Lookup::new(&["aws_internet_gateway"], id))
+        }
+        RouteTarget::NatGateway(id) => {
+            resource.with("nat_gateway_id", Lookup::new(&["aws_nat_gateway"], id))
+        }
+        RouteTarget::TransitGateway(id) => resource.with("transit_gateway_id", id),
+        RouteTarget::VpcPeeringConnection(id) => resource.with("vpc_peering_connection_id", id),
+        RouteTarget::EgressOnlyInternetGateway(id) => resource.with("egress_only_gateway_id", id),
+        RouteTarget::CarrierGateway(id) => resource.with("carrier_gateway_id", id),
+        RouteTarget::LocalGateway(id) => resource.with("local_gateway_id", id),
+        RouteTarget::CoreNetwork(arn) => resource.with("core_network_arn", arn),
+        RouteTarget::NetworkInterface(id) => resource.with("network_interface_id", id),
+    }
+}
+
+fn endpoint_resource(endpoint: VpcEndpoint, vpc_reference: &Reference) -> Resource {
+    let mut resource = Resource::new(
+        "aws_vpc_endpoint",
+        resource_name(&endpoint.id, endpoint.name.as_deref()),
+        &endpoint.id,
+    )
+    .with_identifier("id", &endpoint.id)
+    .with("vpc_id", vpc_reference.clone())
+    .with("service_name", endpoint.service_name)
+    .with("vpc_endpoint_type", &endpoint.endpoint_type);
+
+    if !endpoint.route_table_ids.is_empty() {
+        resource = resource.with(
+            "route_table_ids",
+            endpoint
+                .route_table_ids
+                .into_iter()
+                .map(|id| Lookup::new(&["aws_route_table"], id))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    if !endpoint.subnet_ids.is_empty() {
+        resource = resource.with(
+            "subnet_ids",
+            endpoint
+                .subnet_ids
+                .into_iter()
+                .map(|id| Lookup::new(SUBNET_TYPES, id))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    if !endpoint.security_group_ids.is_empty() {
+        resource = resource.with(
+            "security_group_ids",
+            endpoint
+                .security_group_ids
+                .into_iter()
+                .map(|id| Lookup::new(SECURITY_GROUP_TYPES, id))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    if endpoint.endpoint_type == "Interface" && endpoint.private_dns_enabled {
+        resource = resource.with("private_dns_enabled", true);
+    }
+
+    if let Some(policy) = endpoint.policy {
+        let policy_json: serde_json::Value = serde_json::from_str(&policy).unwrap();
+        resource = resource.with("policy", policy_json);
+    }
+
+    resource.with_optional("tags", tags(endpoint.tags))
+}
+
+/// Names a resource after its `Name` tag, or its ID if it has none.
+fn resource_name(id: &str, name: Option<&str>) -> String {
+    normalize_resource_name(name.unwrap_or(id))
+}
+
+fn tags(tags: Vec<(String, String)>) -> Option<Value> {
+    // Tags with the reserved `aws:` prefix are managed by AWS services and cannot be
+    // set by users.
+    let tags: Vec<_> = tags
+        .into_iter()
+        .filter(|(key, _)| !key.starts_with("aws:"))
+        .collect();
+
+    (!tags.is_empty()).then_some(Value::Map(tags))
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+
+use aws_smithy_types::error::{display::DisplayErrorContext, metadata::ProvideErrorMetadata};
+
+use crate::core::FetchError;
+
+/// Classifies the errors of AWS SDK calls, distinguishing settings that simply are not
+/// configured from failures that would otherwise leave them silently missing.
+pub trait SdkResultExt<T> {
+    /// Treats errors with one of the `not_configured` codes as the setting being absent,
+    /// and any other error as unexpected.
+    fn or_not_configured(self, not_configured: &[&str]) -> Result<Option<T>, FetchError>;
+
+    /// Treats every error as unexpected.
+    fn or_fetch_error(self) -> Result<T, FetchError>;
+}
+
+impl<T, E> SdkResultExt<T> for Result<T, E>
+where
+    E: ProvideErrorMetadata + Error,
+{
+    fn or_not_configured(self, not_configured: &[&str]) -> Result<Option<T>, FetchError> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.code().is_some_and(|code| not_configured.contains(&code)) => Ok(None),
+            Err(e) => Err(fetch_error(e)),
+        }
+    }
+
+    fn or_fetch_error(self) -> Result<T, FetchError> {
+        self.map_err(fetch_error)
+    }
+}
+
+fn fetch_error<E>(e: E) -> FetchError
+where
+    E: ProvideErrorMetadata + Error,
+{
+    FetchError {
+        code: e.code().map(|code| code.to_string()),
+        // Errors that never reached the service, e.g. timeouts, carry no message of their
+        // own, only a chain of sources.
+        message: match e.message() {
+            Some(message) => message.to_string(),
+            None => DisplayErrorContext(&e).to_string(),
+        },
+    }
+}
@@ -0,0 +1,660 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use aws_sdk_iam::types::PolicyScopeType;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use crate::{
+    aws::{
+        error::SdkResultExt,
+        iam::model::{
+            Group, IamIdentityResource, IamResource, InlinePolicy, InstanceProfile,
+            OpenIdConnectProvider, Policy, Role, SamlProvider, User, decode_document,
+        },
+    },
+    core::{FetchError, Report, ResourceFetcher},
+};
+
+#[derive(Clone)]
+pub struct IamFetcher {
+    client: aws_sdk_iam::Client,
+    report: Report,
+}
+
+impl IamFetcher {
+    pub fn new(config: aws_config::SdkConfig) -> Self {
+        Self {
+            client: aws_sdk_iam::Client::new(&config),
+            report: Report::new(),
+        }
+    }
+
+    async fn enrich_role(&self, mut role: Role) -> Role {
+        let (details, inline_policies, attached_policy_arns) = tokio::join!(
+            self.fetch_role_details(&role.name),
+            self.fetch_inline_policies(&role.name),
+            self.fetch_attached_policy_arns(&role.name),
+        );
+
+        // Listing roles leaves out their tags and permissions boundary.
+        if let Some(details) = self.report.check(&role.name, "tags", details).flatten() {
+            role.tags = details.tags;
+            role.permissions_boundary = details.permissions_boundary;
+        }
+
+        if let Some(inline_policies) =
+            self.report
+                .check(&role.name, "inline policies", inline_policies)
+        {
+            role.inline_policies = inline_policies;
+        }
+
+        if let Some(attached_policy_arns) =
+            self.report
+                .check(&role.name, "attached policies", attached_policy_arns)
+        {
+            role.attached_policy_arns = attached_policy_arns;
+        }
+
+        role
+    }
+
+    async fn fetch_role_details(&self, role_name: &str) -> Result<Option<Role>, FetchError> {
+        let output = self
+            .client
+            .get_role()
+            .role_name(role_name)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        Ok(output.role().map(Role::from_aws_role))
+    }
+
+    async fn fetch_inline_policies(
+        &self,
+        role_name: &str,
+    ) -> Result<Vec<InlinePolicy>, FetchError> {
+        let policy_names = self
+            .client
+            .list_role_policies()
+            .role_name(role_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        let mut policies = Vec::new();
+
+        for policy_name in policy_names {
+            let output = self
+                .client
+                .get_role_policy()
+                .role_name(role_name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            policies.push(InlinePolicy {
+                name: policy_name,
+                document: decode_document(output.policy_document()),
+            });
+        }
+
+        Ok(policies)
+    }
+
+    async fn fetch_attached_policy_arns(&self, role_name: &str) -> Result<Vec<String>, FetchError> {
+        let attached_policies = self
+            .client
+            .list_attached_role_policies()
+            .role_name(role_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(attached_policies
+            .iter()
+            .filter_map(|policy| policy.policy_arn())
+            .map(|arn| arn.to_string())
+            .collect())
+    }
+
+    async fn enrich_policy(
+        &self,
+        mut policy: Policy,
+        default_version_id: Option<String>,
+    ) -> Policy {
+        let (details, document) = tokio::join!(
+            self.fetch_policy_details(&policy.arn),
+            self.fetch_policy_document(&policy.arn, default_version_id.as_deref()),
+        );
+
+        // Listing policies leaves out their description and tags.
+        if let Some(details) = self.report.check(&policy.name, "tags", details).flatten() {
+            policy.description = details.description;
+            policy.tags = details.tags;
+        }
+
+        policy.document = self
+            .report
+            .check(&policy.name, "policy document", document)
+            .flatten();
+
+        policy
+    }
+
+    async fn fetch_policy_details(&self, policy_arn: &str) -> Result<Option<Policy>, FetchError> {
+        let output = self
+            .client
+            .get_policy()
+            .policy_arn(policy_arn)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        Ok(output.policy().map(Policy::from_aws_policy))
+    }
+
+    async fn fetch_policy_document(
+        &self,
+        policy_arn: &str,
+        version_id: Option<&str>,
+    ) -> Result<Option<String>, FetchError> {
+        let Some(version_id) = version_id else {
+            return Ok(None);
+        };
+
+        let output = self
+            .client
+            .get_policy_version()
+            .policy_arn(policy_arn)
+            .version_id(version_id)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        Ok(output
+            .policy_version()
+            .and_then(|version| version.document())
+            .map(decode_document))
+    }
+
+    async fn enrich_instance_profile(
+        &self,
+        mut instance_profile: InstanceProfile,
+    ) -> InstanceProfile {
+        let tags = self
+            .client
+            .list_instance_profile_tags()
+            .instance_profile_name(&instance_profile.name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error();
+
+        // Listing instance profiles leaves out their tags.
+        if let Some(tags) = self.report.check(&instance_profile.name, "tags", tags) {
+            instance_profile.tags = tags
+                .iter()
+                .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                .collect();
+        }
+
+        instance_profile
+    }
+}
+
+#[async_trait]
+impl ResourceFetcher for IamFetcher {
+    const SERVICE: &'static str = "iam";
+
+    type Resource = IamResource;
+
+    async fn fetch(&self) -> Result<Vec<IamResource>> {
+        let roles = self
+            .client
+            .list_roles()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let policies = self
+            .client
+            .list_policies()
+            .scope(PolicyScopeType::Local)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let instance_profiles = self
+            .client
+            .list_instance_profiles()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let semaphore = Arc::new(Semaphore::new(5));
+
+        let mut policy_tasks = Vec::new();
+
+        for policy in &policies {
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+            let default_version_id = policy.default_version_id().map(|id| id.to_string());
+            let policy = Policy::from_aws_policy(policy);
+
+            policy_tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_policy(policy, default_version_id).await
+            }));
+        }
+
+        let mut role_tasks = Vec::new();
+
+        for role in roles.iter().map(Role::from_aws_role) {
+            if role.is_aws_managed() {
+                continue;
+            }
+
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+
+            role_tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_role(role).await
+            }));
+        }
+
+        let mut instance_profile_tasks = Vec::new();
+
+        for instance_profile in instance_profiles
+            .iter()
+            .map(InstanceProfile::from_aws_instance_profile)
+        {
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+
+            instance_profile_tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_instance_profile(instance_profile).await
+            }));
+        }
+
+        // Policies come first, since roles are attached to them, and instance profiles
+        // last, since they hold roles.
+        let mut results = Vec::new();
+
+        for task in policy_tasks {
+            results.push(IamResource::Policy(task.await?));
+        }
+
+        for task in role_tasks {
+            results.push(IamResource::Role(Box::new(task.await?)));
+        }
+
+        for task in instance_profile_tasks {
+            results.push(IamResource::InstanceProfile(task.await?));
+        }
+
+        Ok(results)
+    }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
+
+#[derive(Clone)]
+pub struct IamIdentityFetcher {
+    client: aws_sdk_iam::Client,
+    report: Report,
+}
+
+impl IamIdentityFetcher {
+    pub fn new(config: aws_config::SdkConfig) -> Self {
+        Self {
+            client: aws_sdk_iam::Client::new(&config),
+            report: Report::new(),
+        }
+    }
+
+    async fn enrich_user(&self, mut user: User) -> User {
+        let (details, group_names, inline_policies, attached_policy_arns) = tokio::join!(
+            self.fetch_user_details(&user.name),
+            self.fetch_user_group_names(&user.name),
+            self.fetch_user_inline_policies(&user.name),
+            self.fetch_user_attached_policy_arns(&user.name),
+        );
+
+        // Listing users leaves out their tags and permissions boundary.
+        if let Some(details) = self.report.check(&user.name, "tags", details).flatten() {
+            user.tags = details.tags;
+            user.permissions_boundary = details.permissions_boundary;
+        }
+
+        if let Some(group_names) = self.report.check(&user.name, "groups", group_names) {
+            user.group_names = group_names;
+        }
+
+        if let Some(inline_policies) =
+            self.report
+                .check(&user.name, "inline policies", inline_policies)
+        {
+            user.inline_policies = inline_policies;
+        }
+
+        if let Some(attached_policy_arns) =
+            self.report
+                .check(&user.name, "attached policies", attached_policy_arns)
+        {
+            user.attached_policy_arns = attached_policy_arns;
+        }
+
+        user
+    }
+
+    async fn fetch_user_details(&self, user_name: &str) -> Result<Option<User>, FetchError> {
+        let output = self
+            .client
+            .get_user()
+            .user_name(user_name)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        Ok(output.user().map(User::from_aws_user))
+    }
+
+    async fn fetch_user_group_names(&self, user_name: &str) -> Result<Vec<String>, FetchError> {
+        let groups = self
+            .client
+            .list_groups_for_user()
+            .user_name(user_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(groups
+            .iter()
+            .map(|group| group.group_name().to_string())
+            .collect())
+    }
+
+    async fn fetch_user_inline_policies(
+        &self,
+        user_name: &str,
+    ) -> Result<Vec<InlinePolicy>, FetchError> {
+        let policy_names = self
+            .client
+            .list_user_policies()
+            .user_name(user_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        let mut policies = Vec::new();
+
+        for policy_name in policy_names {
+            let output = self
+                .client
+                .get_user_policy()
+                .user_name(user_name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            policies.push(InlinePolicy {
+                name: policy_name,
+                document: decode_document(output.policy_document()),
+            });
+        }
+
+        Ok(policies)
+    }
+
+    async fn fetch_user_attached_policy_arns(
+        &self,
+        user_name: &str,
+    ) -> Result<Vec<String>, FetchError> {
+        let attached_policies = self
+            .client
+            .list_attached_user_policies()
+            .user_name(user_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(attached_policies
+            .iter()
+            .filter_map(|policy| policy.policy_arn())
+            .map(|arn| arn.to_string())
+            .collect())
+    }
+
+    async fn enrich_group(&self, mut group: Group) -> Group {
+        let (inline_policies, attached_policy_arns) = tokio::join!(
+            self.fetch_group_inline_policies(&group.name),
+            self.fetch_group_attached_policy_arns(&group.name),
+        );
+
+        if let Some(inline_policies) =
+            self.report
+                .check(&group.name, "inline policies", inline_policies)
+        {
+            group.inline_policies = inline_policies;
+        }
+
+        if let Some(attached_policy_arns) =
+            self.report
+                .check(&group.name, "attached policies", attached_policy_arns)
+        {
+            group.attached_policy_arns = attached_policy_arns;
+        }
+
+        group
+    }
+
+    async fn fetch_group_inline_policies(
+        &self,
+        group_name: &str,
+    ) -> Result<Vec<InlinePolicy>, FetchError> {
+        let policy_names = self
+            .client
+            .list_group_policies()
+            .group_name(group_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        let mut policies = Vec::new();
+
+        for policy_name in policy_names {
+            let output = self
+                .client
+                .get_group_policy()
+                .group_name(group_name)
+                .policy_name(&policy_name)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            policies.push(InlinePolicy {
+                name: policy_name,
+                document: decode_document(output.policy_document()),
+            });
+        }
+
+        Ok(policies)
+    }
+
+    async fn fetch_group_attached_policy_arns(
+        &self,
+        group_name: &str,
+    ) -> Result<Vec<String>, FetchError> {
+        let attached_policies = self
+            .client
+            .list_attached_group_policies()
+            .group_name(group_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(attached_policies
+            .iter()
+            .filter_map(|policy| policy.policy_arn())
+            .map(|arn| arn.to_string())
+            .collect())
+    }
+
+    async fn fetch_identity_providers(&self) -> Result<Vec<IamIdentityResource>> {
+        let (open_id_connect_providers, saml_providers) = tokio::try_join!(
+            self.client.list_open_id_connect_providers().send(),
+            self.client.list_saml_providers().send(),
+        )?;
+
+        let mut results = Vec::new();
+
+        for arn in open_id_connect_providers
+            .open_id_connect_provider_list()
+            .iter()
+            .filter_map(|provider| provider.arn())
+        {
+            let output = self
+                .client
+                .get_open_id_connect_provider()
+                .open_id_connect_provider_arn(arn)
+                .send()
+                .await
+                .or_fetch_error();
+
+            if let Some(output) = self.report.check(arn, "provider", output) {
+                results.push(IamIdentityResource::OpenIdConnectProvider(
+                    OpenIdConnectProvider::from_aws_output(arn, &output),
+                ));
+            }
+        }
+
+        for arn in saml_providers
+            .saml_provider_list()
+            .iter()
+            .filter_map(|provider| provider.arn())
+        {
+            let output = self
+                .client
+                .get_saml_provider()
+                .saml_provider_arn(arn)
+                .send()
+                .await
+                .or_fetch_error();
+
+            if let Some(output) = self.report.check(arn, "provider", output) {
+                results.push(IamIdentityResource::SamlProvider(
+                    SamlProvider::from_aws_output(arn, &output),
+                ));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl ResourceFetcher for IamIdentityFetcher {
+    const SERVICE: &'static str = "iam";
+
+    type Resource = IamIdentityResource;
+
+    async fn fetch(&self) -> Result<Vec<IamIdentityResource>> {
+        let users = self
+            .client
+            .list_users()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let groups = self
+            .client
+            .list_groups()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let semaphore = Arc::new(Semaphore::new(5));
+
+        let mut group_tasks = Vec::new();
+
+        for group in groups.iter().map(Group::from_aws_group) {
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+
+            group_tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_group(group).await
+            }));
+        }
+
+        let mut user_tasks = Vec::new();
+
+        for user in users.iter().map(User::from_aws_user) {
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+
+            user_tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_user(user).await
+            }));
+        }
+
+        // Identity providers come first, then groups, since users are members of them.
+        let mut results = self.fetch_identity_providers().await?;
+
+        for task in group_tasks {
+            results.push(IamIdentityResource::Group(Box::new(task.await?)));
+        }
+
+        for task in user_tasks {
+            results.push(IamIdentityResource::User(Box::new(task.await?)));
+        }
+
+        Ok(results)
+    }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
@@ -0,0 +1,236 @@
+/// Everything fetched from IAM: roles along with the policies they use and the
+/// instance profiles that pass them to EC2.
+pub enum IamResource {
+    Role(Box<Role>),
+    Policy(Policy),
+    InstanceProfile(InstanceProfile),
+}
+
+pub struct Role {
+    pub name: String,
+    pub arn: String,
+    pub path: String,
+    pub description: Option<String>,
+    pub assume_role_policy: Option<String>,
+    pub max_session_duration: Option<i32>,
+    pub permissions_boundary: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub inline_policies: Vec<InlinePolicy>,
+    pub attached_policy_arns: Vec<String>,
+}
+
+impl Role {
+    pub fn from_aws_role(role: &aws_sdk_iam::types::Role) -> Self {
+        Self {
+            name: role.role_name().to_string(),
+            arn: role.arn().to_string(),
+            path: role.path().to_string(),
+            description: role
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(|description| description.to_string()),
+            assume_role_policy: role.assume_role_policy_document().map(decode_document),
+            max_session_duration: role.max_session_duration(),
+            permissions_boundary: role
+                .permissions_boundary()
+                .and_then(|boundary| boundary.permissions_boundary_arn())
+                .map(|arn| arn.to_string()),
+            tags: tag_pairs(role.tags()),
+            inline_policies: Vec::new(),
+            attached_policy_arns: Vec::new(),
+        }
+    }
+
+    /// Whether the role is created and managed by an AWS service, e.g. a service-linked
+    /// role or one provisioned by IAM Identity Center, and cannot be changed by users.
+    pub fn is_aws_managed(&self) -> bool {
+        self.path.starts_with("/aws-service-role/") || self.path.starts_with("/aws-reserved/")
+    }
+}
+
+/// A policy embedded in the identity it grants permissions to.
+pub struct InlinePolicy {
+    pub name: String,
+    pub document: String,
+}
+
+/// A customer-managed policy. AWS-managed policies are never fetched, and are always
+/// referred to by ARN.
+pub struct Policy {
+    pub name: String,
+    pub arn: String,
+    pub path: String,
+    pub description: Option<String>,
+    pub document: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl Policy {
+    pub fn from_aws_policy(policy: &aws_sdk_iam::types::Policy) -> Self {
+        Self {
+            name: policy.policy_name().unwrap_or_default().to_string(),
+            arn: policy.arn().unwrap_or_default().to_string(),
+            path: policy.path().unwrap_or("/").to_string(),
+            description: policy
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(|description| description.to_string()),
+            document: None,
+            tags: tag_pairs(policy.tags()),
+        }
+    }
+}
+
+pub struct InstanceProfile {
+    pub name: String,
+    pub path: String,
+    pub role_name: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl InstanceProfile {
+    pub fn from_aws_instance_profile(
+        instance_profile: &aws_sdk_iam::types::InstanceProfile,
+    ) -> Self {
+        Self {
+            name: instance_profile.instance_profile_name().to_string(),
+            path: instance_profile.path().to_string(),
+            // An instance profile holds at most one role.
+            role_name: instance_profile
+                .roles()
+                .first()
+                .map(|role| role.role_name().to_string()),
+            tags: tag_pairs(instance_profile.tags()),
+        }
+    }
+}
+
+/// Everything fetched for IAM identities: users and groups along with the external
+/// identity providers roles can trust. Secret material, such as access keys and login
+/// profiles, is never fetched.
+pub enum IamIdentityResource {
+    User(Box<User>),
+    Group(Box<Group>),
+    OpenIdConnectProvider(OpenIdConnectProvider),
+    SamlProvider(SamlProvider),
+}
+
+pub struct User {
+    pub name: String,
+    pub arn: String,
+    pub path: String,
+    pub permissions_boundary: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub group_names: Vec<String>,
+    pub inline_policies: Vec<InlinePolicy>,
+    pub attached_policy_arns: Vec<String>,
+}
+
+impl User {
+    pub fn from_aws_user(user: &aws_sdk_iam::types::User) -> Self {
+        Self {
+            name: user.user_name().to_string(),
+            arn: user.arn().to_string(),
+            path: user.path().to_string(),
+            permissions_boundary: user
+                .permissions_boundary()
+                .and_then(|boundary| boundary.permissions_boundary_arn())
+                .map(|arn| arn.to_string()),
+            tags: tag_pairs(user.tags()),
+            group_names: Vec::new(),
+            inline_policies: Vec::new(),
+            attached_policy_arns: Vec::new(),
+        }
+    }
+}
+
+pub struct Group {
+    pub name: String,
+    pub arn: String,
+    pub path: String,
+    pub inline_policies: Vec<InlinePolicy>,
+    pub attached_policy_arns: Vec<String>,
+}
+
+impl Group {
+    pub fn from_aws_group(group: &aws_sdk_iam::types::Group) -> Self {
+        Self {
+            name: group.group_name().to_string(),
+            arn: group.arn().to_string(),
+            path: group.path().to_string(),
+            inline_policies: Vec::new(),
+            attached_policy_arns: Vec::new(),
+        }
+    }
+}
+
+pub struct OpenIdConnectProvider {
+    pub arn: String,
+    pub url: String,
+    pub client_ids: Vec<String>,
+    pub thumbprints: Vec<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+impl OpenIdConnectProvider {
+    pub fn from_aws_output(
+        arn: &str,
+        output: &aws_sdk_iam::operation::get_open_id_connect_provider::GetOpenIdConnectProviderOutput,
+    ) -> Self {
+        Self {
+            arn: arn.to_string(),
+            url: output.url().unwrap_or_default().to_string(),
+            client_ids: output.client_id_list().to_vec(),
+            thumbprints: output.thumbprint_list().to_vec(),
+            tags: tag_pairs(output.tags()),
+        }
+    }
+
+    /// The provider's name, e.g. `token.actions.githubusercontent.com`, which is the last
+    /// part of its ARN.
+    pub fn name(&self) -> &str {
+        self.arn
+            .split_once(":oidc-provider/")
+            .map_or(&self.arn, |(_, name)| name)
+    }
+}
+
+pub struct SamlProvider {
+    pub arn: String,
+    pub metadata_document: String,
+    pub tags: Vec<(String, String)>,
+}
+
+impl SamlProvider {
+    pub fn from_aws_output(
+        arn: &str,
+        output: &aws_sdk_iam::operation::get_saml_provider::GetSamlProviderOutput,
+    ) -> Self {
+        Self {
+            arn: arn.to_string(),
+            metadata_document: output
+                .saml_metadata_document()
+                .unwrap_or_default()
+                .to_string(),
+            tags: tag_pairs(output.tags()),
+        }
+    }
+
+    /// The name the provider was created with, which is the last part of its ARN.
+    pub fn name(&self) -> &str {
+        self.arn.rsplit('/').next().unwrap_or(&self.arn)
+    }
+}
+
+/// IAM returns policy documents URL-encoded.
+pub fn decode_document(document: &str) -> String {
+    percent_encoding::percent_decode_str(document)
+        .decode_utf8_lossy()
+        .into_owned()
+}
+
+fn tag_pairs(tags: &[aws_sdk_iam::types::Tag]) -> Vec<(String, String)> {
+    tags.iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect()
+}
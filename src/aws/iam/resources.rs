@@ -0,0 +1,355 @@
+use crate::{
+    aws::iam::{
+        Group, IamIdentityResource, IamResource, InlinePolicy, InstanceProfile,
+        OpenIdConnectProvider, Policy, Role, SamlProvider, User,
+    },
+    core::{
+        IntoResources,
+        ir::{Lookup, Reference, Resource, Value},
+    },
+    terraform::normalize_resource_name,
+};
+
+/// Sessions last an hour unless configured otherwise.
+const DEFAULT_MAX_SESSION_DURATION: i32 = 3600;
+
+impl IntoResources for IamResource {
+    fn into_resources(self) -> Vec<Resource> {
+        match self {
+            IamResource::Role(role) => role.into_resources(),
+            IamResource::Policy(policy) => policy.into_resources(),
+            IamResource::InstanceProfile(instance_profile) => instance_profile.into_resources(),
+        }
+    }
+}
+
+impl IntoResources for Role {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        let role = Resource::new("aws_iam_role", &resource_name, &self.name)
+            .with_identifier("name", &self.name)
+            .with_identifier("arn", &self.arn)
+            .with("name", &self.name)
+            .with_optional("path", path(self.path))
+            .with_optional("description", self.description)
+            .with_optional(
+                "assume_role_policy",
+                self.assume_role_policy.as_deref().map(policy_document),
+            )
+            .with_optional(
+                "max_session_duration",
+                self.max_session_duration
+                    .filter(|duration| *duration != DEFAULT_MAX_SESSION_DURATION),
+            )
+            .with_optional(
+                "permissions_boundary",
+                self.permissions_boundary
+                    .map(|arn| Lookup::new(&["aws_iam_policy"], arn)),
+            )
+            .with_optional("tags", tags(self.tags));
+
+        let role_reference = role.reference("name", &self.name);
+
+        let mut resources = vec![role];
+
+        resources.extend(inline_policy_resources(
+            "aws_iam_role_policy",
+            "role",
+            &self.name,
+            &resource_name,
+            &role_reference,
+            self.inline_policies,
+        ));
+
+        resources.extend(policy_attachment_resources(
+            "aws_iam_role_policy_attachment",
+            "role",
+            &self.name,
+            &resource_name,
+            &role_reference,
+            self.attached_policy_arns,
+        ));
+
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+impl IntoResources for Policy {
+    fn into_resources(self) -> Vec<Resource> {
+        let policy = Resource::new(
+            "aws_iam_policy",
+            normalize_resource_name(&self.name),
+            &self.arn,
+        )
+        .with_identifier("arn", &self.arn)
+        .with("name", self.name)
+        .with_optional("path", path(self.path))
+        .with_optional("description", self.description)
+        .with_optional("policy", self.document.as_deref().map(policy_document))
+        .with_optional("tags", tags(self.tags));
+
+        vec![policy]
+    }
+}
+
+impl IntoResources for InstanceProfile {
+    fn into_resources(self) -> Vec<Resource> {
+        let instance_profile = Resource::new(
+            "aws_iam_instance_profile",
+            normalize_resource_name(&self.name),
+            &self.name,
+        )
+        .with_identifier("name", &self.name)
+        .with("name", &self.name)
+        .with_optional("path", path(self.path))
+        .with_optional(
+            "role",
+            self.role_name
+                .map(|role_name| Lookup::new(&["aws_iam_role"], role_name)),
+        )
+        .with_optional("tags", tags(self.tags));
+
+        vec![instance_profile]
+    }
+}
+
+impl IntoResources for IamIdentityResource {
+    fn into_resources(self) -> Vec<Resource> {
+        match self {
+            IamIdentityResource::User(user) => user.into_resources(),
+            IamIdentityResource::Group(group) => group.into_resources(),
+            IamIdentityResource::OpenIdConnectProvider(provider) => provider.into_resources(),
+            IamIdentityResource::SamlProvider(provider) => provider.into_resources(),
+        }
+    }
+}
+
+impl IntoResources for User {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        // Access keys, login profiles and other credentials are deliberately left out:
+        // they cannot be read back, and must not end up in configuration or state.
+        let user = Resource::new("aws_iam_user", &resource_name, &self.name)
+            .with_identifier("name", &self.name)
+            .with_identifier("arn", &self.arn)
+            .with("name", &self.name)
+            .with_optional("path", path(self.path))
+            .with_optional(
+                "permissions_boundary",
+                self.permissions_boundary
+                    .map(|arn| Lookup::new(&["aws_iam_policy"], arn)),
+            )
+            .with_optional("tags", tags(self.tags));
+
+        let user_reference = user.reference("name", &self.name);
+
+        let mut resources = vec![user];
+
+        // Memberships are managed per user rather than per group, since
+        // `aws_iam_group_membership` would remove any member it does not list.
+        if !self.group_names.is_empty() {
+            resources.push(
+                Resource::new(
+                    "aws_iam_user_group_membership",
+                    &resource_name,
+                    format!("{}/{}", self.name, self.group_names.join("/")),
+                )
+                .with("user", user_reference.clone())
+                .with(
+                    "groups",
+                    self.group_names
+                        .into_iter()
+                        .map(|group_name| Lookup::new(&["aws_iam_group"], group_name))
+                        .collect::<Vec<_>>(),
+                ),
+            );
+        }
+
+        resources.extend(inline_policy_resources(
+            "aws_iam_user_policy",
+            "user",
+            &self.name,
+            &resource_name,
+            &user_reference,
+            self.inline_policies,
+        ));
+
+        resources.extend(policy_attachment_resources(
+            "aws_iam_user_policy_attachment",
+            "user",
+            &self.name,
+            &resource_name,
+            &user_reference,
+            self.attached_policy_arns,
+        ));
+
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+impl IntoResources for Group {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        let group = Resource::new("aws_iam_group", &resource_name, &self.name)
+            .with_identifier("name", &self.name)
+            .with_identifier("arn", &self.arn)
+            .with("name", &self.name)
+            .with_optional("path", path(self.path));
+
+        let group_reference = group.reference("name", &self.name);
+
+        let mut resources = vec![group];
+
+        resources.extend(inline_policy_resources(
+            "aws_iam_group_policy",
+            "group",
+            &self.name,
+            &resource_name,
+            &group_reference,
+            self.inline_policies,
+        ));
+
+        resources.extend(policy_attachment_resources(
+            "aws_iam_group_policy_attachment",
+            "group",
+            &self.name,
+            &resource_name,
+            &group_reference,
+            self.attached_policy_arns,
+        ));
+
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+impl IntoResources for OpenIdConnectProvider {
+    fn into_resources(self) -> Vec<Resource> {
+        let mut provider = Resource::new(
+            "aws_iam_openid_connect_provider",
+            normalize_resource_name(self.name()),
+            &self.arn,
+        )
+        .with_identifier("arn", &self.arn)
+        // IAM reports the URL without its scheme, which must be HTTPS.
+        .with("url", format!("https://{}", self.url))
+        .with("client_id_list", self.client_ids);
+
+        // Thumbprints are optional for providers whose certificates IAM can verify
+        // through a trusted certificate authority.
+        if !self.thumbprints.is_empty() {
+            provider = provider.with("thumbprint_list", self.thumbprints);
+        }
+
+        vec![provider.with_optional("tags", tags(self.tags))]
+    }
+}
+
+impl IntoResources for SamlProvider {
+    fn into_resources(self) -> Vec<Resource> {
+        vec![
+            Resource::new(
+                "aws_iam_saml_provider",
+                normalize_resource_name(self.name()),
+                &self.arn,
+            )
+            .with_identifier("arn", &self.arn)
+            .with("name", self.name())
+            .with("saml_metadata_document", self.metadata_document)
+            .with_optional("tags", tags(self.tags)),
+        ]
+    }
+}
+
+/// Converts the inline policies of a role, user or group, whose name is held in the
+/// `identity_attribute` of each policy resource.
+fn inline_policy_resources(
+    resource_type: &str,
+    identity_attribute: &str,
+    identity_name: &str,
+    identity_resource_name: &str,
+    identity_reference: &Reference,
+    policies: Vec<InlinePolicy>,
+) -> Vec<Resource> {
+    policies
+        .into_iter()
+        .map(|policy| {
+            Resource::new(
+                resource_type,
+                format!(
+                    "{}_{}",
+                    identity_resource_name,
+                    normalize_resource_name(&policy.name)
+                ),
+                format!("{}:{}", identity_name, policy.name),
+            )
+            .with("name", policy.name)
+            .with(identity_attribute, identity_reference.clone())
+            .with("policy", policy_document(&policy.document))
+        })
+        .collect()
+}
+
+/// Converts the managed policies attached to a role, user or group, whose name is held in the
+/// `identity_attribute` of each attachment resource.
+fn policy_attachment_resources(
+    resource_type: &str,
+    identity_attribute: &str,
+    identity_name: &str,
+    identity_resource_name: &str,
+    identity_reference: &Reference,
+    policy_arns: Vec<String>,
+) -> Vec<Resource> {
+    policy_arns
+        .into_iter()
+        .map(|policy_arn| {
+            // Policy names are unique within their path, and are the last part of the ARN.
+            let policy_name = policy_arn.rsplit('/').next().unwrap_or(&policy_arn);
+
+            Resource::new(
+                resource_type,
+                format!(
+                    "{}_{}",
+                    identity_resource_name,
+                    normalize_resource_name(policy_name)
+                ),
+                format!("{}/{}", identity_name, policy_arn),
+            )
+            .with(identity_attribute, identity_reference.clone())
+            // AWS-managed policies are never generated, so their ARNs stay literal.
+            .with("policy_arn", Lookup::new(&["aws_iam_policy"], &policy_arn))
+        })
+        .collect()
+}
+
+/// Parses a policy document so that it is rendered as formatted JSON.
+fn policy_document(document: &str) -> serde_json::Value {
+    serde_json::from_str(document).unwrap()
+}
+
+/// The path of an IAM entity, unless it is the default `/`.
+fn path(path: String) -> Option<String> {
+    (path != "/").then_some(path)
+}
+
+fn tags(tags: Vec<(String, String)>) -> Option<Value> {
+    // Tags with the reserved `aws:` prefix are managed by AWS services and cannot be
+    // set by users.
+    let tags: Vec<_> = tags
+        .into_iter()
+        .filter(|(key, _)| !key.starts_with("aws:"))
+        .collect();
+
+    (!tags.is_empty()).then_some(Value::Map(tags))
+}
@@ -0,0 +1,215 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+use crate::{
+    aws::{
+        error::SdkResultExt,
+        lambda::model::{Alias, EventSourceMapping, Function, Permission},
+    },
+    core::{FetchError, Report, ResourceFetcher},
+};
+
+#[derive(Clone)]
+pub struct LambdaFetcher {
+    client: aws_sdk_lambda::Client,
+    report: Report,
+    environment_as_variables: bool,
+}
+
+impl LambdaFetcher {
+    pub fn new(config: aws_config::SdkConfig) -> Self {
+        Self {
+            client: aws_sdk_lambda::Client::new(&config),
+            report: Report::new(),
+            environment_as_variables: false,
+        }
+    }
+
+    /// Supplies functions' environment variable values through input variables rather
+    /// than writing them into the configuration, since they may hold secrets.
+    pub fn with_environment_as_variables(mut self, environment_as_variables: bool) -> Self {
+        self.environment_as_variables = environment_as_variables;
+        self
+    }
+
+    async fn enrich_function(&self, mut function: Function) -> Function {
+        let (tags, aliases, permissions, image_uri) = tokio::join!(
+            self.fetch_tags(&function.arn),
+            self.fetch_aliases(&function.name),
+            self.fetch_permissions(&function.name),
+            self.fetch_image_uri(&function),
+        );
+
+        if let Some(tags) = self.report.check(&function.name, "tags", tags) {
+            function.tags = tags;
+        }
+
+        if let Some(aliases) = self.report.check(&function.name, "aliases", aliases) {
+            function.aliases = aliases;
+        }
+
+        if let Some(permissions) = self.report.check(&function.name, "policy", permissions) {
+            function.permissions = permissions;
+        }
+
+        function.image_uri = self
+            .report
+            .check(&function.name, "image", image_uri)
+            .flatten();
+
+        function.environment_as_variable = self.environment_as_variables;
+
+        function
+    }
+
+    async fn fetch_tags(&self, function_arn: &str) -> Result<Vec<(String, String)>, FetchError> {
+        let output = self
+            .client
+            .list_tags()
+            .resource(function_arn)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        let mut tags: Vec<_> = output
+            .tags()
+            .map(|tags| {
+                tags.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        tags.sort();
+
+        Ok(tags)
+    }
+
+    async fn fetch_aliases(&self, function_name: &str) -> Result<Vec<Alias>, FetchError> {
+        let aliases = self
+            .client
+            .list_aliases()
+            .function_name(function_name)
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await
+            .or_fetch_error()?;
+
+        Ok(aliases.iter().map(Alias::from_aws_alias).collect())
+    }
+
+    async fn fetch_permissions(&self, function_name: &str) -> Result<Vec<Permission>, FetchError> {
+        let output = self
+            .client
+            .get_policy()
+            .function_name(function_name)
+            .send()
+            .await
+            .or_not_configured(&["ResourceNotFoundException"])?;
+
+        let Some(policy) = output.as_ref().and_then(|output| output.policy()) else {
+            return Ok(Vec::new());
+        };
+
+        let policy: serde_json::Value = serde_json::from_str(policy).unwrap();
+
+        Ok(policy
+            .get("Statement")
+            .and_then(|statements| statements.as_array())
+            .map(|statements| {
+                statements
+                    .iter()
+                    .filter_map(Permission::from_policy_statement)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn fetch_image_uri(&self, function: &Function) -> Result<Option<String>, FetchError> {
+        if function.package_type != "Image" {
+            return Ok(None);
+        }
+
+        let output = self
+            .client
+            .get_function()
+            .function_name(&function.name)
+            .send()
+            .await
+            .or_fetch_error()?;
+
+        Ok(output
+            .code()
+            .and_then(|code| code.image_uri())
+            .map(|image_uri| image_uri.to_string()))
+    }
+}
+
+#[async_trait]
+impl ResourceFetcher for LambdaFetcher {
+    const SERVICE: &'static str = "lambda";
+
+    type Resource = Function;
+
+    async fn fetch(&self) -> Result<Vec<Function>> {
+        let functions = self
+            .client
+            .list_functions()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let mappings = self
+            .client
+            .list_event_source_mappings()
+            .into_paginator()
+            .items()
+            .send()
+            .collect::<Result<Vec<_>, _>>()
+            .await?;
+
+        let semaphore = Arc::new(Semaphore::new(5));
+        let mut tasks = Vec::new();
+
+        for function in functions.iter().map(Function::from_aws_configuration) {
+            let semaphore = Arc::clone(&semaphore);
+            let fetcher = self.clone();
+
+            tasks.push(tokio::spawn(async move {
+                let _permit = semaphore.acquire().await.unwrap();
+                fetcher.enrich_function(function).await
+            }));
+        }
+
+        let mut mappings: Vec<_> = mappings
+            .iter()
+            .map(EventSourceMapping::from_aws_mapping)
+            .collect();
+
+        let mut results = Vec::new();
+
+        for task in tasks {
+            let mut function = task.await?;
+
+            let (invoking, others) = mappings
+                .into_iter()
+                .partition(|mapping| mapping.invokes(&function.arn));
+            function.event_source_mappings = invoking;
+            mappings = others;
+
+            results.push(function);
+        }
+
+        Ok(results)
+    }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
@@ -0,0 +1,6 @@
+mod fetcher;
+mod model;
+mod resources;
+
+pub use fetcher::LambdaFetcher;
+pub use model::*;
@@ -0,0 +1,224 @@
+use serde_json::Value as JsonValue;
+
+pub struct Function {
+    pub name: String,
+    pub arn: String,
+    pub package_type: String,
+    pub runtime: Option<String>,
+    pub handler: Option<String>,
+    pub role: String,
+    pub description: Option<String>,
+    pub memory_size: Option<i32>,
+    pub timeout: Option<i32>,
+    pub environment: Vec<(String, String)>,
+    /// Whether environment variable values are supplied through an input variable
+    /// rather than written into the configuration.
+    pub environment_as_variable: bool,
+    pub vpc_config: Option<FunctionVpcConfig>,
+    pub layers: Vec<String>,
+    pub architectures: Vec<String>,
+    pub kms_key_arn: Option<String>,
+    /// The container image of functions packaged as one. The code of other functions
+    /// cannot be fetched back.
+    pub image_uri: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub aliases: Vec<Alias>,
+    pub permissions: Vec<Permission>,
+    pub event_source_mappings: Vec<EventSourceMapping>,
+}
+
+impl Function {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_lambda::types::FunctionConfiguration,
+    ) -> Self {
+        let mut environment: Vec<(String, String)> = configuration
+            .environment()
+            .and_then(|environment| environment.variables())
+            .map(|variables| {
+                variables
+                    .iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // The SDK returns variables as a hash map, whose order is not stable.
+        environment.sort();
+
+        Self {
+            name: configuration
+                .function_name()
+                .unwrap_or_default()
+                .to_string(),
+            arn: configuration.function_arn().unwrap_or_default().to_string(),
+            package_type: configuration
+                .package_type()
+                .map(|package_type| package_type.as_str().to_string())
+                .unwrap_or_else(|| "Zip".to_string()),
+            runtime: configuration
+                .runtime()
+                .map(|runtime| runtime.as_str().to_string()),
+            handler: configuration.handler().map(|handler| handler.to_string()),
+            role: configuration.role().unwrap_or_default().to_string(),
+            description: configuration
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(|description| description.to_string()),
+            memory_size: configuration.memory_size(),
+            timeout: configuration.timeout(),
+            environment,
+            environment_as_variable: false,
+            vpc_config: configuration
+                .vpc_config()
+                .filter(|vpc_config| !vpc_config.subnet_ids().is_empty())
+                .map(|vpc_config| FunctionVpcConfig {
+                    subnet_ids: vpc_config.subnet_ids().to_vec(),
+                    security_group_ids: vpc_config.security_group_ids().to_vec(),
+                }),
+            layers: configuration
+                .layers()
+                .iter()
+                .filter_map(|layer| layer.arn())
+                .map(|arn| arn.to_string())
+                .collect(),
+            architectures: configuration
+                .architectures()
+                .iter()
+                .map(|architecture| architecture.as_str().to_string())
+                .collect(),
+            kms_key_arn: configuration.kms_key_arn().map(|arn| arn.to_string()),
+            image_uri: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            permissions: Vec::new(),
+            event_source_mappings: Vec::new(),
+        }
+    }
+}
+
+pub struct FunctionVpcConfig {
+    pub subnet_ids: Vec<String>,
+    pub security_group_ids: Vec<String>,
+}
+
+pub struct Alias {
+    pub name: String,
+    pub arn: String,
+    pub function_version: String,
+    pub description: Option<String>,
+}
+
+impl Alias {
+    pub fn from_aws_alias(alias: &aws_sdk_lambda::types::AliasConfiguration) -> Self {
+        Self {
+            name: alias.name().unwrap_or_default().to_string(),
+            arn: alias.alias_arn().unwrap_or_default().to_string(),
+            function_version: alias.function_version().unwrap_or_default().to_string(),
+            description: alias
+                .description()
+                .filter(|description| !description.is_empty())
+                .map(|description| description.to_string()),
+        }
+    }
+}
+
+/// A statement of a function's resource-based policy, granting a principal permission
+/// to invoke it.
+pub struct Permission {
+    pub statement_id: String,
+    pub action: String,
+    pub principal: String,
+    pub qualifier: Option<String>,
+    pub source_arn: Option<String>,
+    pub source_account: Option<String>,
+    pub principal_org_id: Option<String>,
+    pub event_source_token: Option<String>,
+    pub function_url_auth_type: Option<String>,
+}
+
+impl Permission {
+    /// Parses the statements of a function policy. Returns `None` for statements that
+    /// do not correspond to a single permission, e.g. ones granting several actions.
+    pub fn from_policy_statement(statement: &JsonValue) -> Option<Self> {
+        let principal = match statement.get("Principal")? {
+            JsonValue::String(principal) => principal.clone(),
+            // Principals are keyed by their kind, e.g. `Service` or `AWS`.
+            JsonValue::Object(principals) => principals.values().next()?.as_str()?.to_string(),
+            _ => return None,
+        };
+
+        // The resource is the function ARN, followed by the qualifier the statement is
+        // limited to, if any.
+        let qualifier = statement
+            .get("Resource")
+            .and_then(JsonValue::as_str)
+            .and_then(|resource| resource.split(':').nth(7))
+            .map(|qualifier| qualifier.to_string());
+
+        Some(Self {
+            statement_id: statement.get("Sid")?.as_str()?.to_string(),
+            action: statement.get("Action")?.as_str()?.to_string(),
+            principal,
+            qualifier,
+            source_arn: condition(statement, "aws:SourceArn"),
+            source_account: condition(statement, "aws:SourceAccount"),
+            principal_org_id: condition(statement, "aws:PrincipalOrgID"),
+            event_source_token: condition(statement, "lambda:EventSourceToken"),
+            function_url_auth_type: condition(statement, "lambda:FunctionUrlAuthType"),
+        })
+    }
+}
+
+/// Finds the value a statement's condition requires for `key`, whatever the operator.
+/// Condition keys are case-insensitive.
+fn condition(statement: &JsonValue, key: &str) -> Option<String> {
+    statement
+        .get("Condition")?
+        .as_object()?
+        .values()
+        .filter_map(JsonValue::as_object)
+        .flat_map(|conditions| conditions.iter())
+        .find(|(condition_key, _)| condition_key.eq_ignore_ascii_case(key))
+        .and_then(|(_, value)| value.as_str())
+        .map(|value| value.to_string())
+}
+
+pub struct EventSourceMapping {
+    pub uuid: String,
+    pub event_source_arn: Option<String>,
+    /// The ARN of the function, or of the function version or alias, the mapping
+    /// invokes.
+    pub function_arn: String,
+    pub batch_size: Option<i32>,
+    pub maximum_batching_window_in_seconds: Option<i32>,
+    pub starting_position: Option<String>,
+    pub enabled: bool,
+}
+
+impl EventSourceMapping {
+    pub fn from_aws_mapping(
+        mapping: &aws_sdk_lambda::types::EventSourceMappingConfiguration,
+    ) -> Self {
+        Self {
+            uuid: mapping.uuid().unwrap_or_default().to_string(),
+            event_source_arn: mapping.event_source_arn().map(|arn| arn.to_string()),
+            function_arn: mapping.function_arn().unwrap_or_default().to_string(),
+            batch_size: mapping.batch_size(),
+            maximum_batching_window_in_seconds: mapping.maximum_batching_window_in_seconds(),
+            starting_position: mapping
+                .starting_position()
+                .map(|position| position.as_str().to_string()),
+            // Mappings are also reported while being created, updated or enabled.
+            enabled: !matches!(mapping.state(), Some("Disabled" | "Disabling")),
+        }
+    }
+
+    /// Whether the mapping invokes `function_arn`, or one of its versions or aliases.
+    pub fn invokes(&self, function_arn: &str) -> bool {
+        self.function_arn == function_arn
+            || self
+                .function_arn
+                .strip_prefix(function_arn)
+                .is_some_and(|qualifier| qualifier.starts_with(':'))
+    }
+}
@@ -0,0 +1,259 @@
+use crate::{
+    aws::lambda::{Alias, EventSourceMapping, Function, Permission},
+    core::{
+        IntoResources,
+        ir::{Attributes, Lookup, Reference, Resource, Value, Variable},
+    },
+    terraform::normalize_resource_name,
+};
+
+const SUBNET_TYPES: &[&str] = &["aws_subnet", "aws_default_subnet"];
+const SECURITY_GROUP_TYPES: &[&str] = &["aws_security_group", "aws_default_security_group"];
+
+/// Functions get 128 MB of memory and time out after 3 seconds unless configured
+/// otherwise.
+const DEFAULT_MEMORY_SIZE: i32 = 128;
+const DEFAULT_TIMEOUT: i32 = 3;
+const DEFAULT_ARCHITECTURE: &str = "x86_64";
+
+impl IntoResources for Function {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        let mut function = Resource::new("aws_lambda_function", &resource_name, &self.name)
+            .with_identifier("function_name", &self.name)
+            .with_identifier("arn", &self.arn)
+            .with("function_name", &self.name)
+            .with("role", Lookup::new(&["aws_iam_role"], &self.role));
+
+        if self.package_type == "Image" {
+            function = function
+                .with("package_type", "Image")
+                .with_optional("image_uri", self.image_uri);
+        } else {
+            // The deployed code cannot be fetched back, so the package is left for the
+            // user to supply, and changes to it are not planned against the import.
+            function = function
+                .with_optional("runtime", self.runtime)
+                .with_optional("handler", self.handler)
+                .with(
+                    "filename",
+                    Variable::new(
+                        format!("{}_filename", resource_name),
+                        format!(
+                            "Path to the deployment package of the {} function",
+                            self.name
+                        ),
+                    )
+                    .with_default(format!("{}.zip", self.name)),
+                )
+                .with_ignore_changes(&["filename", "source_code_hash"]);
+        }
+
+        function = function
+            .with_optional("description", self.description)
+            .with_optional(
+                "memory_size",
+                self.memory_size
+                    .filter(|memory_size| *memory_size != DEFAULT_MEMORY_SIZE),
+            )
+            .with_optional(
+                "timeout",
+                self.timeout.filter(|timeout| *timeout != DEFAULT_TIMEOUT),
+            );
+
+        if self
+            .architectures
+            .iter()
+            .any(|architecture| architecture != DEFAULT_ARCHITECTURE)
+        {
+            function = function.with("architectures", self.architectures);
+        }
+
+        if !self.layers.is_empty() {
+            function = function.with("layers", self.layers);
+        }
+
+        if !self.environment.is_empty() {
+            let variables: Value = if self.environment_as_variable {
+                Variable::new(
+                    format!("{}_environment", resource_name),
+                    format!("Environment variables of the {} function", self.name),
+                )
+                .with_value(Value::Map(self.environment))
+                .sensitive()
+                .into()
+            } else {
+                Value::Map(self.environment)
+            };
+
+            function = function.with(
+                "environment",
+                Value::Block(Attributes::new().with("variables", variables)),
+            );
+        }
+
+        function = function
+            .with_optional(
+                "vpc_config",
+                self.vpc_config.map(|vpc_config| {
+                    Value::Block(
+                        Attributes::new()
+                            .with(
+                                "subnet_ids",
+                                vpc_config
+                                    .subnet_ids
+                                    .into_iter()
+                                    .map(|id| Lookup::new(SUBNET_TYPES, id))
+                                    .collect::<Vec<_>>(),
+                            )
+                            .with(
+                                "security_group_ids",
+                                vpc_config
+                                    .security_group_ids
+                                    .into_iter()
+                                    .map(|id| Lookup::new(SECURITY_GROUP_TYPES, id))
+                                    .collect::<Vec<_>>(),
+                            ),
+                    )
+                }),
+            )
+            .with_optional(
+                "kms_key_arn",
+                self.kms_key_arn
+                    .map(|arn| Lookup::new(&["aws_kms_key"], arn)),
+            )
+            .with_optional("tags", tags(self.tags));
+
+        let function_reference = function.reference("function_name", &self.name);
+
+        let mut resources = vec![function];
+
+        resources.extend(
+            self.aliases.into_iter().map(|alias| {
+                alias_resource(&self.name, &resource_name, &function_reference, alias)
+            }),
+        );
+
+        resources.extend(self.permissions.into_iter().map(|permission| {
+            permission_resource(&self.name, &resource_name, &function_reference, permission)
+        }));
+
+        resources.extend(
+            self.event_source_mappings
+                .into_iter()
+                .map(|mapping| event_source_mapping_resource(&resource_name, mapping)),
+        );
+
+        resources
+            .into_iter()
+            .map(|resource| resource.with_group(&resource_name))
+            .collect()
+    }
+}
+
+fn alias_resource(
+    function_name: &str,
+    function_resource_name: &str,
+    function_reference: &Reference,
+    alias: Alias,
+) -> Resource {
+    Resource::new(
+        "aws_lambda_alias",
+        format!(
+            "{}_{}",
+            function_resource_name,
+            normalize_resource_name(&alias.name)
+        ),
+        format!("{}/{}", function_name, alias.name),
+    )
+    .with_identifier("arn", &alias.arn)
+    .with("name", alias.name)
+    .with("function_name", function_reference.clone())
+    .with("function_version", alias.function_version)
+    .with_optional("description", alias.description)
+}
+
+fn permission_resource(
+    function_name: &str,
+    function_resource_name: &str,
+    function_reference: &Reference,
+    permission: Permission,
+) -> Resource {
+    // Permissions limited to a version or alias are imported through the qualified
+    // function name.
+    let import_id = match &permission.qualifier {
+        Some(qualifier) => format!(
+            "{}:{}/{}",
+            function_name, qualifier, permission.statement_id
+        ),
+        None => format!("{}/{}", function_name, permission.statement_id),
+    };
+
+    Resource::new(
+        "aws_lambda_permission",
+        format!(
+            "{}_{}",
+            function_resource_name,
+            normalize_resource_name(&permission.statement_id)
+        ),
+        import_id,
+    )
+    .with("statement_id", permission.statement_id)
+    .with("action", permission.action)
+    .with("function_name", function_reference.clone())
+    .with("principal", permission.principal)
+    .with_optional("qualifier", permission.qualifier)
+    .with_optional("source_arn", permission.source_arn)
+    .with_optional("source_account", permission.source_account)
+    .with_optional("principal_org_id", permission.principal_org_id)
+    .with_optional("event_source_token", permission.event_source_token)
+    .with_optional("function_url_auth_type", permission.function_url_auth_type)
+}
+
+fn event_source_mapping_resource(
+    function_resource_name: &str,
+    mapping: EventSourceMapping,
+) -> Resource {
+    // Mappings have no name of their own, so they are named after their UUID.
+    let mut resource = Resource::new(
+        "aws_lambda_event_source_mapping",
+        format!(
+            "{}_{}",
+            function_resource_name,
+            normalize_resource_name(&mapping.uuid)
+        ),
+        &mapping.uuid,
+    )
+    .with(
+        "function_name",
+        Lookup::new(
+            &["aws_lambda_alias", "aws_lambda_function"],
+            &mapping.function_arn,
+        ),
+    )
+    .with_optional("event_source_arn", mapping.event_source_arn)
+    .with_optional("batch_size", mapping.batch_size)
+    .with_optional(
+        "maximum_batching_window_in_seconds",
+        mapping.maximum_batching_window_in_seconds,
+    )
+    .with_optional("starting_position", mapping.starting_position);
+
+    if !mapping.enabled {
+        resource = resource.with("enabled", false);
+    }
+
+    resource
+}
+
+fn tags(tags: Vec<(String, String)>) -> Option<Value> {
+    // Tags with the reserved `aws:` prefix are managed by AWS services and cannot be
+    // set by users.
+    let tags: Vec<_> = tags
+        .into_iter()
+        .filter(|(key, _)| !key.starts_with("aws:"))
+        .collect();
+
+    (!tags.is_empty()).then_some(Value::Map(tags))
+}
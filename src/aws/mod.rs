@@ -1 +1,5 @@
+pub mod ec2;
+pub mod error;
+pub mod iam;
+pub mod lambda;
 pub mod s3;
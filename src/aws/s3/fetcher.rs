@@ -1,160 +1,894 @@
 use anyhow::Result;
 use async_trait::async_trait;
-use std::sync::Arc;
+use aws_config::Region;
+use aws_sdk_s3::types::BucketLocationConstraint;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 use tokio::sync::Semaphore;
 
 use crate::{
-    aws::s3::{
-        BucketEncryption, BucketLogging, BucketVersioning,
-        model::{Bucket, BucketBuilder, BucketPublicAccessBlock},
+    aws::{
+        error::SdkResultExt,
+        s3::{
+            AnalyticsConfiguration, BucketAccelerate, BucketAcl, BucketCors, BucketEncryption,
+            BucketLifecycle, BucketLogging, BucketNotification, BucketObjectLock,
+            BucketOwnershipControls, BucketReplication, BucketRequestPayment, BucketVersioning,
+            BucketWebsite, IntelligentTieringConfiguration, InventoryConfiguration,
+            MetricsConfiguration,
+            model::{
+                AccessPoint, Bucket, BucketBuilder, BucketPublicAccessBlock,
+                MultiRegionAccessPoint, MultiRegionAccessPointRegion, S3Resource,
+            },
+        },
     },
-    core::ResourceFetcher,
+    core::{FetchError, Report, ResourceFetcher},
 };
 
+/// Multi-Region Access Points are managed through a control plane that only runs in
+/// this region.
+const MULTI_REGION_ACCESS_POINT_REGION: &str = "us-west-2";
+
+/// Buckets created before regional endpoints existed report no location constraint.
+const DEFAULT_BUCKET_REGION: &str = "us-east-1";
+
 #[derive(Clone)]
 pub struct S3Fetcher {
+    config: aws_config::SdkConfig,
     client: aws_sdk_s3::Client,
+    /// Clients for the regions buckets live in, since S3 rejects or redirects requests
+    /// about a bucket made through another region's endpoint.
+    regional_clients: Arc<Mutex<HashMap<String, aws_sdk_s3::Client>>>,
+    report: Report,
+    control_client: aws_sdk_s3control::Client,
+    multi_region_control_client: aws_sdk_s3control::Client,
+    sts_client: aws_sdk_sts::Client,
 }
 
 impl S3Fetcher {
     pub fn new(config: aws_config::SdkConfig) -> Self {
+        let multi_region_control_config = aws_sdk_s3control::config::Builder::from(&config)
+            .region(Region::from_static(MULTI_REGION_ACCESS_POINT_REGION))
+            .build();
+
         Self {
             client: aws_sdk_s3::Client::new(&config),
+            regional_clients: Arc::new(Mutex::new(HashMap::new())),
+            report: Report::new(),
+            control_client: aws_sdk_s3control::Client::new(&config),
+            multi_region_control_client: aws_sdk_s3control::Client::from_conf(
+                multi_region_control_config,
+            ),
+            sts_client: aws_sdk_sts::Client::new(&config),
+            config,
+        }
+    }
+
+    /// A fetcher whose S3 requests go to `region`, reusing a pooled client if one has
+    /// already been built for it.
+    fn in_region(&self, region: &str) -> Self {
+        let client = self
+            .regional_clients
+            .lock()
+            .unwrap()
+            .entry(region.to_string())
+            .or_insert_with(|| {
+                let config = aws_sdk_s3::config::Builder::from(&self.config)
+                    .region(Region::new(region.to_string()))
+                    .build();
+                aws_sdk_s3::Client::from_conf(config)
+            })
+            .clone();
+
+        Self {
+            client,
+            ..self.clone()
         }
     }
 
-    async fn enrich_bucket(&self, bucket_name: String) -> Bucket {
+    async fn fetch_bucket_region(&self, bucket_name: &str) -> Result<String, FetchError> {
+        self.client
+            .get_bucket_location()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| match output.location_constraint() {
+                // `EU` is a legacy alias for Ireland.
+                Some(BucketLocationConstraint::Eu) => "eu-west-1".to_string(),
+                Some(constraint) if !constraint.as_str().is_empty() => {
+                    constraint.as_str().to_string()
+                }
+                _ => DEFAULT_BUCKET_REGION.to_string(),
+            })
+    }
+
+    async fn enrich_bucket(&self, bucket_name: String, region: Option<String>) -> Bucket {
+        let region = match region {
+            Some(region) => Some(region),
+            None => self.report.check(
+                &bucket_name,
+                "region",
+                self.fetch_bucket_region(&bucket_name).await,
+            ),
+        };
+
+        let fetcher = match &region {
+            Some(region) => self.in_region(region),
+            None => self.clone(),
+        };
+
         let mut builder = BucketBuilder::new(bucket_name.clone());
 
-        let (policy, public_access_block, encryption, versioning, logging) = tokio::join!(
-            self.fetch_policy(&bucket_name),
-            self.fetch_public_access_block(&bucket_name),
-            self.fetch_encryption(&bucket_name),
-            self.fetch_versioning(&bucket_name),
-            self.fetch_logging(&bucket_name),
+        if let Some(region) = region {
+            builder = builder.with_region(region);
+        }
+
+        let (
+            policy,
+            public_access_block,
+            encryption,
+            versioning,
+            logging,
+            lifecycle,
+            replication,
+            cors,
+            website,
+            notification,
+            object_lock,
+            ownership_controls,
+            acl,
+            tags,
+            accelerate,
+            request_payment,
+            intelligent_tiering,
+            inventory,
+            analytics,
+            metrics,
+        ) = tokio::join!(
+            fetcher.fetch_policy(&bucket_name),
+            fetcher.fetch_public_access_block(&bucket_name),
+            fetcher.fetch_encryption(&bucket_name),
+            fetcher.fetch_versioning(&bucket_name),
+            fetcher.fetch_logging(&bucket_name),
+            fetcher.fetch_lifecycle(&bucket_name),
+            fetcher.fetch_replication(&bucket_name),
+            fetcher.fetch_cors(&bucket_name),
+            fetcher.fetch_website(&bucket_name),
+            fetcher.fetch_notification(&bucket_name),
+            fetcher.fetch_object_lock(&bucket_name),
+            fetcher.fetch_ownership_controls(&bucket_name),
+            fetcher.fetch_acl(&bucket_name),
+            fetcher.fetch_tags(&bucket_name),
+            fetcher.fetch_accelerate(&bucket_name),
+            fetcher.fetch_request_payment(&bucket_name),
+            fetcher.fetch_intelligent_tiering(&bucket_name),
+            fetcher.fetch_inventory(&bucket_name),
+            fetcher.fetch_analytics(&bucket_name),
+            fetcher.fetch_metrics(&bucket_name),
         );
 
-        if let Some(policy) = policy {
+        if let Some(policy) = self.report.check(&bucket_name, "policy", policy).flatten() {
             builder = builder.with_policy(policy);
         }
 
-        if let Some(public_access_block) = public_access_block {
+        if let Some(public_access_block) = self
+            .report
+            .check(&bucket_name, "public access block", public_access_block)
+            .flatten()
+        {
             builder = builder.with_public_access_block(public_access_block);
         }
 
-        if let Some(encryption) = encryption {
+        if let Some(encryption) = self
+            .report
+            .check(&bucket_name, "encryption", encryption)
+            .flatten()
+        {
             builder = builder.with_encryption(encryption);
         }
 
-        if let Some(versioning) = versioning {
+        if let Some(versioning) = self
+            .report
+            .check(&bucket_name, "versioning", versioning)
+            .flatten()
+        {
             builder = builder.with_versioning(versioning);
         }
 
-        if let Some(logging) = logging {
+        if let Some(logging) = self
+            .report
+            .check(&bucket_name, "logging", logging)
+            .flatten()
+        {
             builder = builder.with_logging(logging);
         }
 
-        builder.build()
+        if let Some(lifecycle) = self
+            .report
+            .check(&bucket_name, "lifecycle", lifecycle)
+            .flatten()
+        {
+            builder = builder.with_lifecycle(lifecycle);
+        }
+
+        if let Some(replication) = self
+            .report
+            .check(&bucket_name, "replication", replication)
+            .flatten()
+        {
+            builder = builder.with_replication(replication);
+        }
+
+        if let Some(cors) = self.report.check(&bucket_name, "cors", cors).flatten() {
+            builder = builder.with_cors(cors);
+        }
+
+        if let Some(website) = self
+            .report
+            .check(&bucket_name, "website", website)
+            .flatten()
+        {
+            builder = builder.with_website(website);
+        }
+
+        if let Some(notification) = self
+            .report
+            .check(&bucket_name, "notification", notification)
+            .flatten()
+        {
+            builder = builder.with_notification(notification);
+        }
+
+        if let Some(object_lock) = self
+            .report
+            .check(&bucket_name, "object lock", object_lock)
+            .flatten()
+        {
+            builder = builder.with_object_lock(object_lock);
+        }
+
+        if let Some(ownership_controls) = self
+            .report
+            .check(&bucket_name, "ownership controls", ownership_controls)
+            .flatten()
+        {
+            builder = builder.with_ownership_controls(ownership_controls);
+        }
+
+        if let Some(acl) = self.report.check(&bucket_name, "acl", acl).flatten() {
+            builder = builder.with_acl(acl);
+        }
+
+        if let Some(accelerate) = self
+            .report
+            .check(&bucket_name, "accelerate", accelerate)
+            .flatten()
+        {
+            builder = builder.with_accelerate(accelerate);
+        }
+
+        if let Some(request_payment) = self
+            .report
+            .check(&bucket_name, "request payment", request_payment)
+            .flatten()
+        {
+            builder = builder.with_request_payment(request_payment);
+        }
+
+        builder
+            .with_tags(
+                self.report
+                    .check(&bucket_name, "tags", tags)
+                    .unwrap_or_default(),
+            )
+            .with_intelligent_tiering(
+                self.report
+                    .check(&bucket_name, "intelligent tiering", intelligent_tiering)
+                    .unwrap_or_default(),
+            )
+            .with_inventory(
+                self.report
+                    .check(&bucket_name, "inventory", inventory)
+                    .unwrap_or_default(),
+            )
+            .with_analytics(
+                self.report
+                    .check(&bucket_name, "analytics", analytics)
+                    .unwrap_or_default(),
+            )
+            .with_metrics(
+                self.report
+                    .check(&bucket_name, "metrics", metrics)
+                    .unwrap_or_default(),
+            )
+            .build()
     }
 
-    async fn fetch_policy(&self, bucket_name: &str) -> Option<String> {
-        self.client
+    async fn fetch_policy(&self, bucket_name: &str) -> Result<Option<String>, FetchError> {
+        let output = self
+            .client
             .get_bucket_policy()
             .bucket(bucket_name)
             .send()
             .await
-            .ok()
-            .and_then(|output| output.policy().map(|p| p.to_string()))
+            .or_not_configured(&["NoSuchBucketPolicy"])?;
+
+        Ok(output.and_then(|output| output.policy().map(|p| p.to_string())))
     }
 
     async fn fetch_public_access_block(
         &self,
         bucket_name: &str,
-    ) -> Option<BucketPublicAccessBlock> {
-        self.client
+    ) -> Result<Option<BucketPublicAccessBlock>, FetchError> {
+        let output = self
+            .client
             .get_public_access_block()
             .bucket(bucket_name)
             .send()
             .await
-            .ok()
-            .and_then(|output| {
-                output
-                    .public_access_block_configuration()
-                    .map(|cfg| BucketPublicAccessBlock {
-                        block_public_acls: cfg.block_public_acls().unwrap_or(true),
-                        block_public_policy: cfg.block_public_policy().unwrap_or(true),
-                        ignore_public_acls: cfg.ignore_public_acls().unwrap_or(true),
-                        restrict_public_buckets: cfg.restrict_public_buckets().unwrap_or(true),
-                    })
-            })
+            .or_not_configured(&["NoSuchPublicAccessBlockConfiguration"])?;
+
+        Ok(output.and_then(|output| {
+            output
+                .public_access_block_configuration()
+                .map(|cfg| BucketPublicAccessBlock {
+                    block_public_acls: cfg.block_public_acls().unwrap_or(true),
+                    block_public_policy: cfg.block_public_policy().unwrap_or(true),
+                    ignore_public_acls: cfg.ignore_public_acls().unwrap_or(true),
+                    restrict_public_buckets: cfg.restrict_public_buckets().unwrap_or(true),
+                })
+        }))
     }
 
-    async fn fetch_encryption(&self, bucket_name: &str) -> Option<BucketEncryption> {
-        self.client
+    async fn fetch_encryption(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketEncryption>, FetchError> {
+        let output = self
+            .client
             .get_bucket_encryption()
             .bucket(bucket_name)
             .send()
             .await
-            .ok()
-            .and_then(|output| {
-                output
-                    .server_side_encryption_configuration()
-                    .and_then(|cfg| BucketEncryption::from_aws_rules(cfg.rules()))
-            })
+            .or_not_configured(&["ServerSideEncryptionConfigurationNotFoundError"])?;
+
+        Ok(output.and_then(|output| {
+            output
+                .server_side_encryption_configuration()
+                .and_then(|cfg| BucketEncryption::from_aws_rules(cfg.rules()))
+        }))
     }
 
-    async fn fetch_versioning(&self, bucket_name: &str) -> Option<BucketVersioning> {
+    async fn fetch_versioning(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketVersioning>, FetchError> {
         self.client
             .get_bucket_versioning()
             .bucket(bucket_name)
             .send()
             .await
-            .ok()
-            .and_then(|output| {
+            .or_fetch_error()
+            .map(|output| {
                 output.status().map(|status| BucketVersioning {
                     status: status.to_string(),
                 })
             })
     }
 
-    async fn fetch_logging(&self, bucket_name: &str) -> Option<BucketLogging> {
+    async fn fetch_logging(&self, bucket_name: &str) -> Result<Option<BucketLogging>, FetchError> {
         self.client
             .get_bucket_logging()
             .bucket(bucket_name)
             .send()
             .await
-            .ok()
-            .and_then(|output| {
+            .or_fetch_error()
+            .map(|output| {
                 output.logging_enabled().map(|enabled| BucketLogging {
                     target_bucket: enabled.target_bucket().to_string(),
                     target_prefix: enabled.target_prefix().to_string(),
                 })
             })
     }
+
+    async fn fetch_lifecycle(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketLifecycle>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_lifecycle_configuration()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["NoSuchLifecycleConfiguration"])?;
+
+        Ok(output.and_then(|output| BucketLifecycle::from_aws_output(&output)))
+    }
+
+    async fn fetch_replication(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketReplication>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_replication()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["ReplicationConfigurationNotFoundError"])?;
+
+        Ok(output.and_then(|output| {
+            output
+                .replication_configuration()
+                .map(BucketReplication::from_aws_configuration)
+        }))
+    }
+
+    async fn fetch_cors(&self, bucket_name: &str) -> Result<Option<BucketCors>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_cors()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["NoSuchCORSConfiguration"])?;
+
+        Ok(output.and_then(|output| BucketCors::from_aws_rules(output.cors_rules())))
+    }
+
+    async fn fetch_website(&self, bucket_name: &str) -> Result<Option<BucketWebsite>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_website()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["NoSuchWebsiteConfiguration"])?;
+
+        Ok(output.map(|output| BucketWebsite::from_aws_output(&output)))
+    }
+
+    async fn fetch_notification(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketNotification>, FetchError> {
+        self.client
+            .get_bucket_notification_configuration()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| BucketNotification::from_aws_output(&output))
+    }
+
+    async fn fetch_object_lock(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketObjectLock>, FetchError> {
+        let output = self
+            .client
+            .get_object_lock_configuration()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["ObjectLockConfigurationNotFoundError"])?;
+
+        Ok(output.and_then(|output| {
+            output
+                .object_lock_configuration()
+                .map(BucketObjectLock::from_aws_configuration)
+        }))
+    }
+
+    async fn fetch_ownership_controls(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketOwnershipControls>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_ownership_controls()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["OwnershipControlsNotFoundError"])?;
+
+        Ok(output.and_then(|output| {
+            output
+                .ownership_controls()
+                .and_then(|controls| controls.rules().first())
+                .map(|rule| BucketOwnershipControls {
+                    object_ownership: rule.object_ownership().to_string(),
+                })
+        }))
+    }
+
+    async fn fetch_acl(&self, bucket_name: &str) -> Result<Option<BucketAcl>, FetchError> {
+        self.client
+            .get_bucket_acl()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| Some(BucketAcl::from_aws_output(&output)))
+    }
+
+    async fn fetch_tags(&self, bucket_name: &str) -> Result<Vec<(String, String)>, FetchError> {
+        let output = self
+            .client
+            .get_bucket_tagging()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_not_configured(&["NoSuchTagSet"])?;
+
+        Ok(output
+            .map(|output| {
+                output
+                    .tag_set()
+                    .iter()
+                    .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn fetch_accelerate(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketAccelerate>, FetchError> {
+        self.client
+            .get_bucket_accelerate_configuration()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| {
+                output.status().map(|status| BucketAccelerate {
+                    status: status.to_string(),
+                })
+            })
+    }
+
+    async fn fetch_request_payment(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Option<BucketRequestPayment>, FetchError> {
+        self.client
+            .get_bucket_request_payment()
+            .bucket(bucket_name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| {
+                output.payer().map(|payer| BucketRequestPayment {
+                    payer: payer.to_string(),
+                })
+            })
+    }
+
+    async fn fetch_intelligent_tiering(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Vec<IntelligentTieringConfiguration>, FetchError> {
+        let mut configurations = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let output = self
+                .client
+                .list_bucket_intelligent_tiering_configurations()
+                .bucket(bucket_name)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            configurations.extend(
+                output
+                    .intelligent_tiering_configuration_list()
+                    .iter()
+                    .map(IntelligentTieringConfiguration::from_aws_configuration),
+            );
+
+            match output.next_continuation_token() {
+                Some(token) if output.is_truncated() == Some(true) => {
+                    continuation_token = Some(token.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(configurations)
+    }
+
+    async fn fetch_inventory(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Vec<InventoryConfiguration>, FetchError> {
+        let mut configurations = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let output = self
+                .client
+                .list_bucket_inventory_configurations()
+                .bucket(bucket_name)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            configurations.extend(
+                output
+                    .inventory_configuration_list()
+                    .iter()
+                    .map(InventoryConfiguration::from_aws_configuration),
+            );
+
+            match output.next_continuation_token() {
+                Some(token) if output.is_truncated() == Some(true) => {
+                    continuation_token = Some(token.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(configurations)
+    }
+
+    async fn fetch_analytics(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Vec<AnalyticsConfiguration>, FetchError> {
+        let mut configurations = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let output = self
+                .client
+                .list_bucket_analytics_configurations()
+                .bucket(bucket_name)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            configurations.extend(
+                output
+                    .analytics_configuration_list()
+                    .iter()
+                    .map(AnalyticsConfiguration::from_aws_configuration),
+            );
+
+            match output.next_continuation_token() {
+                Some(token) if output.is_truncated() == Some(true) => {
+                    continuation_token = Some(token.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(configurations)
+    }
+
+    async fn fetch_metrics(
+        &self,
+        bucket_name: &str,
+    ) -> Result<Vec<MetricsConfiguration>, FetchError> {
+        let mut configurations = Vec::new();
+        let mut continuation_token = None;
+
+        loop {
+            let output = self
+                .client
+                .list_bucket_metrics_configurations()
+                .bucket(bucket_name)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await
+                .or_fetch_error()?;
+
+            configurations.extend(
+                output
+                    .metrics_configuration_list()
+                    .iter()
+                    .map(MetricsConfiguration::from_aws_configuration),
+            );
+
+            match output.next_continuation_token() {
+                Some(token) if output.is_truncated() == Some(true) => {
+                    continuation_token = Some(token.to_string());
+                }
+                _ => break,
+            }
+        }
+
+        Ok(configurations)
+    }
+
+    /// Fetches access points and Multi-Region Access Points. Both are addressed through
+    /// the account that owns them, so they are skipped if it cannot be determined.
+    async fn fetch_access_points(&self) -> Vec<S3Resource> {
+        let identity = self
+            .sts_client
+            .get_caller_identity()
+            .send()
+            .await
+            .or_fetch_error();
+
+        let Some(identity) = self.report.check("account", "caller identity", identity) else {
+            return Vec::new();
+        };
+
+        let account_id = identity.account().unwrap_or_default().to_string();
+
+        let mut resources = Vec::new();
+
+        let mut pages = self
+            .control_client
+            .list_access_points()
+            .account_id(&account_id)
+            .into_paginator()
+            .send();
+
+        while let Some(page) = pages.next().await {
+            let Some(page) = self
+                .report
+                .check(&account_id, "access points", page.or_fetch_error())
+            else {
+                break;
+            };
+
+            for access_point in page.access_point_list() {
+                let (public_access_block, policy) = tokio::join!(
+                    self.fetch_access_point_public_access_block(&account_id, access_point.name()),
+                    self.fetch_access_point_policy(&account_id, access_point.name()),
+                );
+
+                resources.push(S3Resource::AccessPoint(AccessPoint {
+                    name: access_point.name().to_string(),
+                    account_id: account_id.clone(),
+                    arn: access_point
+                        .access_point_arn()
+                        .unwrap_or_default()
+                        .to_string(),
+                    bucket: access_point.bucket().to_string(),
+                    bucket_account_id: access_point.bucket_account_id().map(|id| id.to_string()),
+                    vpc_id: access_point
+                        .vpc_configuration()
+                        .map(|vpc| vpc.vpc_id().to_string()),
+                    public_access_block: self
+                        .report
+                        .check(
+                            access_point.name(),
+                            "public access block",
+                            public_access_block,
+                        )
+                        .flatten(),
+                    policy: self
+                        .report
+                        .check(access_point.name(), "policy", policy)
+                        .flatten(),
+                }));
+            }
+        }
+
+        let mut pages = self
+            .multi_region_control_client
+            .list_multi_region_access_points()
+            .account_id(&account_id)
+            .into_paginator()
+            .send();
+
+        while let Some(page) = pages.next().await {
+            let Some(page) = self.report.check(
+                &account_id,
+                "multi-region access points",
+                page.or_fetch_error(),
+            ) else {
+                break;
+            };
+
+            for access_point in page.access_points() {
+                resources.push(S3Resource::MultiRegionAccessPoint(MultiRegionAccessPoint {
+                    name: access_point.name().unwrap_or_default().to_string(),
+                    account_id: account_id.clone(),
+                    public_access_block: access_point
+                        .public_access_block()
+                        .map(control_public_access_block),
+                    regions: access_point
+                        .regions()
+                        .iter()
+                        .map(|region| MultiRegionAccessPointRegion {
+                            bucket: region.bucket().unwrap_or_default().to_string(),
+                            bucket_account_id: region.bucket_account_id().map(|id| id.to_string()),
+                        })
+                        .collect(),
+                }));
+            }
+        }
+
+        resources
+    }
+
+    async fn fetch_access_point_public_access_block(
+        &self,
+        account_id: &str,
+        name: &str,
+    ) -> Result<Option<BucketPublicAccessBlock>, FetchError> {
+        self.control_client
+            .get_access_point()
+            .account_id(account_id)
+            .name(name)
+            .send()
+            .await
+            .or_fetch_error()
+            .map(|output| {
+                output
+                    .public_access_block_configuration()
+                    .map(control_public_access_block)
+            })
+    }
+
+    async fn fetch_access_point_policy(
+        &self,
+        account_id: &str,
+        name: &str,
+    ) -> Result<Option<String>, FetchError> {
+        let output = self
+            .control_client
+            .get_access_point_policy()
+            .account_id(account_id)
+            .name(name)
+            .send()
+            .await
+            .or_not_configured(&["NoSuchAccessPointPolicy"])?;
+
+        Ok(output.and_then(|output| output.policy().map(|p| p.to_string())))
+    }
+}
+
+fn control_public_access_block(
+    cfg: &aws_sdk_s3control::types::PublicAccessBlockConfiguration,
+) -> BucketPublicAccessBlock {
+    BucketPublicAccessBlock {
+        block_public_acls: cfg.block_public_acls().unwrap_or(true),
+        block_public_policy: cfg.block_public_policy().unwrap_or(true),
+        ignore_public_acls: cfg.ignore_public_acls().unwrap_or(true),
+        restrict_public_buckets: cfg.restrict_public_buckets().unwrap_or(true),
+    }
 }
 
 #[async_trait]
 impl ResourceFetcher for S3Fetcher {
-    type Resource = Bucket;
+    const SERVICE: &'static str = "s3";
 
-    async fn fetch(&self) -> Result<Vec<Bucket>> {
+    type Resource = S3Resource;
+
+    async fn fetch(&self) -> Result<Vec<S3Resource>> {
         let buckets = self.client.list_buckets().send().await?;
-        let bucket_names: Vec<String> = buckets
+        let bucket_names: Vec<(String, Option<String>)> = buckets
             .buckets()
             .iter()
-            .filter_map(|b| b.name().map(|n| n.to_string()))
+            .filter_map(|b| {
+                b.name()
+                    .map(|n| (n.to_string(), b.bucket_region().map(|r| r.to_string())))
+            })
             .collect();
 
         let semaphore = Arc::new(Semaphore::new(5));
         let mut tasks = Vec::new();
 
-        for bucket_name in bucket_names {
+        for (bucket_name, region) in bucket_names {
             let semaphore = Arc::clone(&semaphore);
             let fetcher = self.clone();
 
             let task = tokio::spawn(async move {
                 let _permit = semaphore.acquire().await.unwrap();
-                fetcher.enrich_bucket(bucket_name).await
+                fetcher.enrich_bucket(bucket_name, region).await
             });
 
             tasks.push(task);
@@ -163,9 +897,15 @@ impl ResourceFetcher for S3Fetcher {
         let mut results = Vec::new();
         for task in tasks {
             let result = task.await?;
-            results.push(result);
+            results.push(S3Resource::Bucket(Box::new(result)));
         }
 
+        results.extend(self.fetch_access_points().await);
+
         Ok(results)
     }
+
+    fn report(&self) -> Report {
+        self.report.clone()
+    }
 }
@@ -1,5 +1,6 @@
 mod fetcher;
 mod model;
+mod resources;
 
 pub use fetcher::S3Fetcher;
 pub use model::*;
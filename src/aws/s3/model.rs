@@ -1,20 +1,33 @@
-use crate::core::IntoTerraform;
+/// Everything fetched from S3: buckets along with the access points in front of them.
+pub enum S3Resource {
+    Bucket(Box<Bucket>),
+    AccessPoint(AccessPoint),
+    MultiRegionAccessPoint(MultiRegionAccessPoint),
+}
 
 pub struct Bucket {
     pub name: String,
+    pub region: Option<String>,
     pub policy: Option<String>,
     pub public_access_block: Option<BucketPublicAccessBlock>,
     pub encryption: Option<BucketEncryption>,
     pub versioning: Option<BucketVersioning>,
     pub logging: Option<BucketLogging>,
-}
-
-impl IntoTerraform for Bucket {
-    type TerraformResource = crate::terraform::s3::Bucket;
-
-    fn into_terraform(self) -> Self::TerraformResource {
-        self.into()
-    }
+    pub lifecycle: Option<BucketLifecycle>,
+    pub replication: Option<BucketReplication>,
+    pub cors: Option<BucketCors>,
+    pub website: Option<BucketWebsite>,
+    pub notification: Option<BucketNotification>,
+    pub object_lock: Option<BucketObjectLock>,
+    pub ownership_controls: Option<BucketOwnershipControls>,
+    pub acl: Option<BucketAcl>,
+    pub tags: Vec<(String, String)>,
+    pub accelerate: Option<BucketAccelerate>,
+    pub request_payment: Option<BucketRequestPayment>,
+    pub intelligent_tiering: Vec<IntelligentTieringConfiguration>,
+    pub inventory: Vec<InventoryConfiguration>,
+    pub analytics: Vec<AnalyticsConfiguration>,
+    pub metrics: Vec<MetricsConfiguration>,
 }
 
 pub struct BucketPublicAccessBlock {
@@ -54,27 +67,938 @@ pub struct BucketLogging {
     pub target_prefix: String,
 }
 
+pub struct BucketLifecycle {
+    pub rules: Vec<LifecycleRule>,
+    pub transition_default_minimum_object_size: Option<String>,
+}
+
+impl BucketLifecycle {
+    pub fn from_aws_output(
+        output: &aws_sdk_s3::operation::get_bucket_lifecycle_configuration::GetBucketLifecycleConfigurationOutput,
+    ) -> Option<Self> {
+        if output.rules().is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            rules: output
+                .rules()
+                .iter()
+                .map(LifecycleRule::from_aws_rule)
+                .collect(),
+            transition_default_minimum_object_size: output
+                .transition_default_minimum_object_size()
+                .map(|size| size.to_string()),
+        })
+    }
+}
+
+pub struct LifecycleRule {
+    pub id: String,
+    pub status: String,
+    pub filter: Option<LifecycleRuleFilter>,
+    pub expiration: Option<LifecycleExpiration>,
+    pub transitions: Vec<LifecycleTransition>,
+    pub noncurrent_version_expiration: Option<NoncurrentVersionExpiration>,
+    pub noncurrent_version_transitions: Vec<NoncurrentVersionTransition>,
+    pub abort_incomplete_multipart_upload_days: Option<i32>,
+}
+
+impl LifecycleRule {
+    pub fn from_aws_rule(rule: &aws_sdk_s3::types::LifecycleRule) -> Self {
+        // Rules created before filters were introduced carry a top-level prefix instead,
+        // which is equivalent to a prefix-only filter.
+        #[allow(deprecated)]
+        let filter = match (rule.filter(), rule.prefix()) {
+            (Some(filter), _) => Some(LifecycleRuleFilter::from_aws_filter(filter)),
+            (None, Some(prefix)) => Some(LifecycleRuleFilter {
+                prefix: Some(prefix.to_string()),
+                tag: None,
+                object_size_greater_than: None,
+                object_size_less_than: None,
+                and: None,
+            }),
+            (None, None) => None,
+        };
+
+        Self {
+            id: rule.id().unwrap_or_default().to_string(),
+            status: rule.status().to_string(),
+            filter,
+            expiration: rule.expiration().map(|expiration| LifecycleExpiration {
+                date: expiration.date().and_then(format_date),
+                days: expiration.days(),
+                expired_object_delete_marker: expiration.expired_object_delete_marker(),
+            }),
+            transitions: rule
+                .transitions()
+                .iter()
+                .map(|transition| LifecycleTransition {
+                    date: transition.date().and_then(format_date),
+                    days: transition.days(),
+                    storage_class: transition
+                        .storage_class()
+                        .map(|class| class.to_string())
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            noncurrent_version_expiration: rule.noncurrent_version_expiration().map(|expiration| {
+                NoncurrentVersionExpiration {
+                    noncurrent_days: expiration.noncurrent_days(),
+                    newer_noncurrent_versions: expiration.newer_noncurrent_versions(),
+                }
+            }),
+            noncurrent_version_transitions: rule
+                .noncurrent_version_transitions()
+                .iter()
+                .map(|transition| NoncurrentVersionTransition {
+                    noncurrent_days: transition.noncurrent_days(),
+                    newer_noncurrent_versions: transition.newer_noncurrent_versions(),
+                    storage_class: transition
+                        .storage_class()
+                        .map(|class| class.to_string())
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            abort_incomplete_multipart_upload_days: rule
+                .abort_incomplete_multipart_upload()
+                .and_then(|abort| abort.days_after_initiation()),
+        }
+    }
+}
+
+pub struct LifecycleRuleFilter {
+    pub prefix: Option<String>,
+    pub tag: Option<(String, String)>,
+    pub object_size_greater_than: Option<i64>,
+    pub object_size_less_than: Option<i64>,
+    pub and: Option<LifecycleRuleAndOperator>,
+}
+
+impl LifecycleRuleFilter {
+    fn from_aws_filter(filter: &aws_sdk_s3::types::LifecycleRuleFilter) -> Self {
+        Self {
+            prefix: filter.prefix().map(|prefix| prefix.to_string()),
+            tag: filter
+                .tag()
+                .map(|tag| (tag.key().to_string(), tag.value().to_string())),
+            object_size_greater_than: filter.object_size_greater_than(),
+            object_size_less_than: filter.object_size_less_than(),
+            and: filter.and().map(|and| LifecycleRuleAndOperator {
+                prefix: and.prefix().map(|prefix| prefix.to_string()),
+                tags: and
+                    .tags()
+                    .iter()
+                    .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                    .collect(),
+                object_size_greater_than: and.object_size_greater_than(),
+                object_size_less_than: and.object_size_less_than(),
+            }),
+        }
+    }
+}
+
+pub struct LifecycleRuleAndOperator {
+    pub prefix: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub object_size_greater_than: Option<i64>,
+    pub object_size_less_than: Option<i64>,
+}
+
+pub struct LifecycleExpiration {
+    pub date: Option<String>,
+    pub days: Option<i32>,
+    pub expired_object_delete_marker: Option<bool>,
+}
+
+pub struct LifecycleTransition {
+    pub date: Option<String>,
+    pub days: Option<i32>,
+    pub storage_class: String,
+}
+
+pub struct NoncurrentVersionExpiration {
+    pub noncurrent_days: Option<i32>,
+    pub newer_noncurrent_versions: Option<i32>,
+}
+
+pub struct NoncurrentVersionTransition {
+    pub noncurrent_days: Option<i32>,
+    pub newer_noncurrent_versions: Option<i32>,
+    pub storage_class: String,
+}
+
+pub struct BucketReplication {
+    pub role: String,
+    pub rules: Vec<ReplicationRule>,
+}
+
+impl BucketReplication {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_s3::types::ReplicationConfiguration,
+    ) -> Self {
+        Self {
+            role: configuration.role().to_string(),
+            rules: configuration
+                .rules()
+                .iter()
+                .map(ReplicationRule::from_aws_rule)
+                .collect(),
+        }
+    }
+}
+
+pub struct ReplicationRule {
+    pub id: Option<String>,
+    pub priority: Option<i32>,
+    pub status: String,
+    /// The prefix of rules created before filters were introduced.
+    pub prefix: Option<String>,
+    pub filter: Option<ReplicationRuleFilter>,
+    pub destination: Option<ReplicationDestination>,
+    pub delete_marker_replication_status: Option<String>,
+    pub existing_object_replication_status: Option<String>,
+    pub replica_modifications_status: Option<String>,
+    pub sse_kms_encrypted_objects_status: Option<String>,
+}
+
+impl ReplicationRule {
+    pub fn from_aws_rule(rule: &aws_sdk_s3::types::ReplicationRule) -> Self {
+        let source_selection_criteria = rule.source_selection_criteria();
+
+        Self {
+            id: rule.id().map(|id| id.to_string()),
+            priority: rule.priority(),
+            status: rule.status().to_string(),
+            #[allow(deprecated)]
+            prefix: rule.prefix().map(|prefix| prefix.to_string()),
+            filter: rule.filter().map(|filter| ReplicationRuleFilter {
+                prefix: filter.prefix().map(|prefix| prefix.to_string()),
+                tag: filter
+                    .tag()
+                    .map(|tag| (tag.key().to_string(), tag.value().to_string())),
+                and: filter.and().map(|and| ReplicationRuleAndOperator {
+                    prefix: and.prefix().map(|prefix| prefix.to_string()),
+                    tags: and
+                        .tags()
+                        .iter()
+                        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                        .collect(),
+                }),
+            }),
+            destination: rule
+                .destination()
+                .map(ReplicationDestination::from_aws_destination),
+            delete_marker_replication_status: rule
+                .delete_marker_replication()
+                .and_then(|replication| replication.status())
+                .map(|status| status.to_string()),
+            existing_object_replication_status: rule
+                .existing_object_replication()
+                .map(|replication| replication.status().to_string()),
+            replica_modifications_status: source_selection_criteria
+                .and_then(|criteria| criteria.replica_modifications())
+                .map(|modifications| modifications.status().to_string()),
+            sse_kms_encrypted_objects_status: source_selection_criteria
+                .and_then(|criteria| criteria.sse_kms_encrypted_objects())
+                .map(|objects| objects.status().to_string()),
+        }
+    }
+}
+
+pub struct ReplicationRuleFilter {
+    pub prefix: Option<String>,
+    pub tag: Option<(String, String)>,
+    pub and: Option<ReplicationRuleAndOperator>,
+}
+
+pub struct ReplicationRuleAndOperator {
+    pub prefix: Option<String>,
+    pub tags: Vec<(String, String)>,
+}
+
+pub struct ReplicationDestination {
+    /// The ARN of the destination bucket.
+    pub bucket: String,
+    pub account: Option<String>,
+    pub storage_class: Option<String>,
+    pub access_control_translation_owner: Option<String>,
+    pub replica_kms_key_id: Option<String>,
+    pub replication_time: Option<ReplicationTime>,
+    pub metrics: Option<ReplicationMetrics>,
+}
+
+impl ReplicationDestination {
+    fn from_aws_destination(destination: &aws_sdk_s3::types::Destination) -> Self {
+        Self {
+            bucket: destination.bucket().to_string(),
+            account: destination.account().map(|account| account.to_string()),
+            storage_class: destination.storage_class().map(|class| class.to_string()),
+            access_control_translation_owner: destination
+                .access_control_translation()
+                .map(|translation| translation.owner().to_string()),
+            replica_kms_key_id: destination
+                .encryption_configuration()
+                .and_then(|encryption| encryption.replica_kms_key_id())
+                .map(|id| id.to_string()),
+            replication_time: destination.replication_time().map(|time| ReplicationTime {
+                status: time.status().to_string(),
+                minutes: time.time().and_then(|value| value.minutes()),
+            }),
+            metrics: destination.metrics().map(|metrics| ReplicationMetrics {
+                status: metrics.status().to_string(),
+                event_threshold_minutes: metrics
+                    .event_threshold()
+                    .and_then(|value| value.minutes()),
+            }),
+        }
+    }
+}
+
+pub struct ReplicationTime {
+    pub status: String,
+    pub minutes: Option<i32>,
+}
+
+pub struct ReplicationMetrics {
+    pub status: String,
+    pub event_threshold_minutes: Option<i32>,
+}
+
+pub struct BucketCors {
+    pub rules: Vec<CorsRule>,
+}
+
+impl BucketCors {
+    pub fn from_aws_rules(rules: &[aws_sdk_s3::types::CorsRule]) -> Option<Self> {
+        if rules.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            rules: rules
+                .iter()
+                .map(|rule| CorsRule {
+                    id: rule.id().map(|id| id.to_string()),
+                    allowed_headers: rule.allowed_headers().to_vec(),
+                    allowed_methods: rule.allowed_methods().to_vec(),
+                    allowed_origins: rule.allowed_origins().to_vec(),
+                    expose_headers: rule.expose_headers().to_vec(),
+                    max_age_seconds: rule.max_age_seconds(),
+                })
+                .collect(),
+        })
+    }
+}
+
+pub struct CorsRule {
+    pub id: Option<String>,
+    pub allowed_headers: Vec<String>,
+    pub allowed_methods: Vec<String>,
+    pub allowed_origins: Vec<String>,
+    pub expose_headers: Vec<String>,
+    pub max_age_seconds: Option<i32>,
+}
+
+pub struct BucketWebsite {
+    pub index_document: Option<String>,
+    pub error_document: Option<String>,
+    pub redirect_all_requests_to: Option<WebsiteRedirectAllRequestsTo>,
+    pub routing_rules: Vec<WebsiteRoutingRule>,
+}
+
+impl BucketWebsite {
+    pub fn from_aws_output(
+        output: &aws_sdk_s3::operation::get_bucket_website::GetBucketWebsiteOutput,
+    ) -> Self {
+        Self {
+            index_document: output
+                .index_document()
+                .map(|document| document.suffix().to_string()),
+            error_document: output
+                .error_document()
+                .map(|document| document.key().to_string()),
+            redirect_all_requests_to: output.redirect_all_requests_to().map(|redirect| {
+                WebsiteRedirectAllRequestsTo {
+                    host_name: redirect.host_name().to_string(),
+                    protocol: redirect.protocol().map(|protocol| protocol.to_string()),
+                }
+            }),
+            routing_rules: output
+                .routing_rules()
+                .iter()
+                .map(|rule| WebsiteRoutingRule {
+                    condition: rule.condition().map(|condition| WebsiteRoutingCondition {
+                        http_error_code_returned_equals: condition
+                            .http_error_code_returned_equals()
+                            .map(|code| code.to_string()),
+                        key_prefix_equals: condition
+                            .key_prefix_equals()
+                            .map(|prefix| prefix.to_string()),
+                    }),
+                    redirect: rule.redirect().map(|redirect| WebsiteRoutingRedirect {
+                        host_name: redirect.host_name().map(|host| host.to_string()),
+                        http_redirect_code: redirect
+                            .http_redirect_code()
+                            .map(|code| code.to_string()),
+                        protocol: redirect.protocol().map(|protocol| protocol.to_string()),
+                        replace_key_prefix_with: redirect
+                            .replace_key_prefix_with()
+                            .map(|prefix| prefix.to_string()),
+                        replace_key_with: redirect.replace_key_with().map(|key| key.to_string()),
+                    }),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct WebsiteRedirectAllRequestsTo {
+    pub host_name: String,
+    pub protocol: Option<String>,
+}
+
+pub struct WebsiteRoutingRule {
+    pub condition: Option<WebsiteRoutingCondition>,
+    pub redirect: Option<WebsiteRoutingRedirect>,
+}
+
+pub struct WebsiteRoutingCondition {
+    pub http_error_code_returned_equals: Option<String>,
+    pub key_prefix_equals: Option<String>,
+}
+
+pub struct WebsiteRoutingRedirect {
+    pub host_name: Option<String>,
+    pub http_redirect_code: Option<String>,
+    pub protocol: Option<String>,
+    pub replace_key_prefix_with: Option<String>,
+    pub replace_key_with: Option<String>,
+}
+
+pub struct BucketNotification {
+    pub topics: Vec<NotificationTarget>,
+    pub queues: Vec<NotificationTarget>,
+    pub lambda_functions: Vec<NotificationTarget>,
+    pub eventbridge: bool,
+}
+
+impl BucketNotification {
+    pub fn from_aws_output(
+        output: &aws_sdk_s3::operation::get_bucket_notification_configuration::GetBucketNotificationConfigurationOutput,
+    ) -> Option<Self> {
+        let notification = Self {
+            topics: output
+                .topic_configurations()
+                .iter()
+                .map(|topic| {
+                    NotificationTarget::from_aws_parts(
+                        topic.id(),
+                        topic.topic_arn(),
+                        topic.events(),
+                        topic.filter(),
+                    )
+                })
+                .collect(),
+            queues: output
+                .queue_configurations()
+                .iter()
+                .map(|queue| {
+                    NotificationTarget::from_aws_parts(
+                        queue.id(),
+                        queue.queue_arn(),
+                        queue.events(),
+                        queue.filter(),
+                    )
+                })
+                .collect(),
+            lambda_functions: output
+                .lambda_function_configurations()
+                .iter()
+                .map(|function| {
+                    NotificationTarget::from_aws_parts(
+                        function.id(),
+                        function.lambda_function_arn(),
+                        function.events(),
+                        function.filter(),
+                    )
+                })
+                .collect(),
+            eventbridge: output.event_bridge_configuration().is_some(),
+        };
+
+        // Buckets without notifications still return an empty configuration.
+        if notification.topics.is_empty()
+            && notification.queues.is_empty()
+            && notification.lambda_functions.is_empty()
+            && !notification.eventbridge
+        {
+            return None;
+        }
+
+        Some(notification)
+    }
+}
+
+/// An SNS topic, SQS queue or Lambda function that bucket events are sent to.
+pub struct NotificationTarget {
+    pub id: Option<String>,
+    pub arn: String,
+    pub events: Vec<String>,
+    pub filter_prefix: Option<String>,
+    pub filter_suffix: Option<String>,
+}
+
+impl NotificationTarget {
+    fn from_aws_parts(
+        id: Option<&str>,
+        arn: &str,
+        events: &[aws_sdk_s3::types::Event],
+        filter: Option<&aws_sdk_s3::types::NotificationConfigurationFilter>,
+    ) -> Self {
+        let filter_rules = filter
+            .and_then(|filter| filter.key())
+            .map(|key| key.filter_rules())
+            .unwrap_or_default();
+        let filter_value = |name: aws_sdk_s3::types::FilterRuleName| {
+            filter_rules
+                .iter()
+                .find(|rule| rule.name() == Some(&name))
+                .and_then(|rule| rule.value())
+                .map(|value| value.to_string())
+        };
+
+        Self {
+            id: id.map(|id| id.to_string()),
+            arn: arn.to_string(),
+            events: events.iter().map(|event| event.to_string()).collect(),
+            filter_prefix: filter_value(aws_sdk_s3::types::FilterRuleName::Prefix),
+            filter_suffix: filter_value(aws_sdk_s3::types::FilterRuleName::Suffix),
+        }
+    }
+}
+
+pub struct BucketObjectLock {
+    pub enabled: bool,
+    pub default_retention: Option<ObjectLockRetention>,
+}
+
+impl BucketObjectLock {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_s3::types::ObjectLockConfiguration,
+    ) -> Self {
+        Self {
+            enabled: configuration.object_lock_enabled()
+                == Some(&aws_sdk_s3::types::ObjectLockEnabled::Enabled),
+            default_retention: configuration
+                .rule()
+                .and_then(|rule| rule.default_retention())
+                .map(|retention| ObjectLockRetention {
+                    mode: retention.mode().map(|mode| mode.to_string()),
+                    days: retention.days(),
+                    years: retention.years(),
+                }),
+        }
+    }
+}
+
+pub struct ObjectLockRetention {
+    pub mode: Option<String>,
+    pub days: Option<i32>,
+    pub years: Option<i32>,
+}
+
+pub struct BucketOwnershipControls {
+    pub object_ownership: String,
+}
+
+impl BucketOwnershipControls {
+    pub const BUCKET_OWNER_ENFORCED: &str = "BucketOwnerEnforced";
+}
+
+pub struct BucketAcl {
+    pub owner_id: Option<String>,
+    pub owner_display_name: Option<String>,
+    pub grants: Vec<AclGrant>,
+}
+
+impl BucketAcl {
+    pub fn from_aws_output(
+        output: &aws_sdk_s3::operation::get_bucket_acl::GetBucketAclOutput,
+    ) -> Self {
+        Self {
+            owner_id: output
+                .owner()
+                .and_then(|owner| owner.id())
+                .map(|id| id.to_string()),
+            owner_display_name: output
+                .owner()
+                .and_then(|owner| owner.display_name())
+                .map(|name| name.to_string()),
+            grants: output
+                .grants()
+                .iter()
+                .filter_map(|grant| {
+                    let grantee = grant.grantee()?;
+
+                    Some(AclGrant {
+                        grantee_type: grantee.r#type().to_string(),
+                        grantee_id: grantee.id().map(|id| id.to_string()),
+                        grantee_uri: grantee.uri().map(|uri| uri.to_string()),
+                        grantee_email_address: grantee
+                            .email_address()
+                            .map(|email| email.to_string()),
+                        permission: grant.permission()?.to_string(),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether the ACL is the default one every bucket is created with, granting the
+    /// owner full control and nobody else anything.
+    pub fn is_private(&self) -> bool {
+        match self.grants.as_slice() {
+            [grant] => {
+                grant.grantee_type == "CanonicalUser"
+                    && grant.grantee_id.is_some()
+                    && grant.grantee_id == self.owner_id
+                    && grant.permission == "FULL_CONTROL"
+            }
+            _ => false,
+        }
+    }
+}
+
+pub struct AclGrant {
+    pub grantee_type: String,
+    pub grantee_id: Option<String>,
+    pub grantee_uri: Option<String>,
+    pub grantee_email_address: Option<String>,
+    pub permission: String,
+}
+
+pub struct BucketAccelerate {
+    pub status: String,
+}
+
+pub struct BucketRequestPayment {
+    pub payer: String,
+}
+
+impl BucketRequestPayment {
+    pub const BUCKET_OWNER: &str = "BucketOwner";
+}
+
+pub struct IntelligentTieringConfiguration {
+    pub id: String,
+    pub status: String,
+    pub prefix: Option<String>,
+    /// Tags an object must carry, from either a single-tag or a combined filter.
+    pub tags: Vec<(String, String)>,
+    pub tierings: Vec<IntelligentTiering>,
+}
+
+impl IntelligentTieringConfiguration {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_s3::types::IntelligentTieringConfiguration,
+    ) -> Self {
+        let filter = configuration.filter();
+        let and = filter.and_then(|filter| filter.and());
+
+        let prefix = filter
+            .and_then(|filter| filter.prefix())
+            .or_else(|| and.and_then(|and| and.prefix()));
+        let tags = filter
+            .and_then(|filter| filter.tag())
+            .into_iter()
+            .chain(and.map(|and| and.tags()).unwrap_or_default())
+            .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+            .collect();
+
+        Self {
+            id: configuration.id().to_string(),
+            status: configuration.status().to_string(),
+            prefix: prefix.map(|prefix| prefix.to_string()),
+            tags,
+            tierings: configuration
+                .tierings()
+                .iter()
+                .map(|tiering| IntelligentTiering {
+                    access_tier: tiering.access_tier().to_string(),
+                    days: tiering.days(),
+                })
+                .collect(),
+        }
+    }
+}
+
+pub struct IntelligentTiering {
+    pub access_tier: String,
+    pub days: i32,
+}
+
+pub struct InventoryConfiguration {
+    pub id: String,
+    pub enabled: bool,
+    pub included_object_versions: String,
+    pub optional_fields: Vec<String>,
+    pub frequency: Option<String>,
+    pub prefix: Option<String>,
+    pub destination: Option<InventoryDestination>,
+}
+
+impl InventoryConfiguration {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_s3::types::InventoryConfiguration,
+    ) -> Self {
+        Self {
+            id: configuration.id().to_string(),
+            enabled: configuration.is_enabled(),
+            included_object_versions: configuration.included_object_versions().to_string(),
+            optional_fields: configuration
+                .optional_fields()
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            frequency: configuration
+                .schedule()
+                .map(|schedule| schedule.frequency().to_string()),
+            prefix: configuration
+                .filter()
+                .map(|filter| filter.prefix().to_string()),
+            destination: configuration
+                .destination()
+                .and_then(|destination| destination.s3_bucket_destination())
+                .map(|destination| {
+                    let encryption = destination.encryption();
+
+                    InventoryDestination {
+                        bucket_arn: destination.bucket().to_string(),
+                        format: destination.format().to_string(),
+                        account_id: destination.account_id().map(|id| id.to_string()),
+                        prefix: destination.prefix().map(|prefix| prefix.to_string()),
+                        sse_s3: encryption
+                            .and_then(|encryption| encryption.sses3())
+                            .is_some(),
+                        sse_kms_key_id: encryption
+                            .and_then(|encryption| encryption.ssekms())
+                            .map(|kms| kms.key_id().to_string()),
+                    }
+                }),
+        }
+    }
+}
+
+pub struct InventoryDestination {
+    pub bucket_arn: String,
+    pub format: String,
+    pub account_id: Option<String>,
+    pub prefix: Option<String>,
+    pub sse_s3: bool,
+    pub sse_kms_key_id: Option<String>,
+}
+
+pub struct AnalyticsConfiguration {
+    pub id: String,
+    pub filter: Option<ConfigurationFilter>,
+    pub data_export: Option<AnalyticsDataExport>,
+}
+
+impl AnalyticsConfiguration {
+    pub fn from_aws_configuration(
+        configuration: &aws_sdk_s3::types::AnalyticsConfiguration,
+    ) -> Self {
+        use aws_sdk_s3::types::AnalyticsFilter;
+
+        let filter = configuration.filter().and_then(|filter| match filter {
+            AnalyticsFilter::Prefix(prefix) => Some(ConfigurationFilter {
+                prefix: Some(prefix.clone()),
+                ..Default::default()
+            }),
+            AnalyticsFilter::Tag(tag) => Some(ConfigurationFilter {
+                tags: tag_pairs([tag]),
+                ..Default::default()
+            }),
+            AnalyticsFilter::And(and) => Some(ConfigurationFilter {
+                prefix: and.prefix().map(|prefix| prefix.to_string()),
+                tags: tag_pairs(and.tags()),
+                ..Default::default()
+            }),
+            _ => None,
+        });
+
+        let data_export = configuration
+            .storage_class_analysis()
+            .and_then(|analysis| analysis.data_export());
+
+        Self {
+            id: configuration.id().to_string(),
+            filter,
+            data_export: data_export.and_then(|data_export| {
+                let destination = data_export.destination()?.s3_bucket_destination()?;
+
+                Some(AnalyticsDataExport {
+                    output_schema_version: data_export.output_schema_version().to_string(),
+                    bucket_arn: destination.bucket().to_string(),
+                    bucket_account_id: destination.bucket_account_id().map(|id| id.to_string()),
+                    format: destination.format().to_string(),
+                    prefix: destination.prefix().map(|prefix| prefix.to_string()),
+                })
+            }),
+        }
+    }
+}
+
+pub struct AnalyticsDataExport {
+    pub output_schema_version: String,
+    pub bucket_arn: String,
+    pub bucket_account_id: Option<String>,
+    pub format: String,
+    pub prefix: Option<String>,
+}
+
+pub struct MetricsConfiguration {
+    pub id: String,
+    pub filter: Option<ConfigurationFilter>,
+}
+
+impl MetricsConfiguration {
+    pub fn from_aws_configuration(configuration: &aws_sdk_s3::types::MetricsConfiguration) -> Self {
+        use aws_sdk_s3::types::MetricsFilter;
+
+        let filter = configuration.filter().and_then(|filter| match filter {
+            MetricsFilter::Prefix(prefix) => Some(ConfigurationFilter {
+                prefix: Some(prefix.clone()),
+                ..Default::default()
+            }),
+            MetricsFilter::Tag(tag) => Some(ConfigurationFilter {
+                tags: tag_pairs([tag]),
+                ..Default::default()
+            }),
+            MetricsFilter::AccessPointArn(arn) => Some(ConfigurationFilter {
+                access_point_arn: Some(arn.clone()),
+                ..Default::default()
+            }),
+            MetricsFilter::And(and) => Some(ConfigurationFilter {
+                prefix: and.prefix().map(|prefix| prefix.to_string()),
+                tags: tag_pairs(and.tags()),
+                access_point_arn: and.access_point_arn().map(|arn| arn.to_string()),
+            }),
+            _ => None,
+        });
+
+        Self {
+            id: configuration.id().to_string(),
+            filter,
+        }
+    }
+}
+
+/// The objects an analytics or metrics configuration applies to. Every condition
+/// present must match.
+#[derive(Default)]
+pub struct ConfigurationFilter {
+    pub prefix: Option<String>,
+    pub tags: Vec<(String, String)>,
+    pub access_point_arn: Option<String>,
+}
+
+fn tag_pairs<'a>(
+    tags: impl IntoIterator<Item = &'a aws_sdk_s3::types::Tag>,
+) -> Vec<(String, String)> {
+    tags.into_iter()
+        .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+        .collect()
+}
+
+pub struct AccessPoint {
+    pub name: String,
+    pub account_id: String,
+    pub arn: String,
+    pub bucket: String,
+    pub bucket_account_id: Option<String>,
+    pub vpc_id: Option<String>,
+    pub public_access_block: Option<BucketPublicAccessBlock>,
+    pub policy: Option<String>,
+}
+
+pub struct MultiRegionAccessPoint {
+    pub name: String,
+    pub account_id: String,
+    pub public_access_block: Option<BucketPublicAccessBlock>,
+    pub regions: Vec<MultiRegionAccessPointRegion>,
+}
+
+pub struct MultiRegionAccessPointRegion {
+    pub bucket: String,
+    pub bucket_account_id: Option<String>,
+}
+
+/// Formats a date as RFC 3339, e.g. `2024-01-01T00:00:00Z`.
+fn format_date(date: &aws_sdk_s3::primitives::DateTime) -> Option<String> {
+    date.fmt(aws_sdk_s3::primitives::DateTimeFormat::DateTime)
+        .ok()
+}
+
 pub struct BucketBuilder {
     name: String,
+    region: Option<String>,
     policy: Option<String>,
     public_access_block: Option<BucketPublicAccessBlock>,
     encryption: Option<BucketEncryption>,
     versioning: Option<BucketVersioning>,
     logging: Option<BucketLogging>,
+    lifecycle: Option<BucketLifecycle>,
+    replication: Option<BucketReplication>,
+    cors: Option<BucketCors>,
+    website: Option<BucketWebsite>,
+    notification: Option<BucketNotification>,
+    object_lock: Option<BucketObjectLock>,
+    ownership_controls: Option<BucketOwnershipControls>,
+    acl: Option<BucketAcl>,
+    tags: Vec<(String, String)>,
+    accelerate: Option<BucketAccelerate>,
+    request_payment: Option<BucketRequestPayment>,
+    intelligent_tiering: Vec<IntelligentTieringConfiguration>,
+    inventory: Vec<InventoryConfiguration>,
+    analytics: Vec<AnalyticsConfiguration>,
+    metrics: Vec<MetricsConfiguration>,
 }
 
 impl BucketBuilder {
     pub fn new(name: String) -> Self {
         Self {
             name,
+            region: None,
             policy: None,
             public_access_block: None,
             encryption: None,
             versioning: None,
             logging: None,
+            lifecycle: None,
+            replication: None,
+            cors: None,
+            website: None,
+            notification: None,
+            object_lock: None,
+            ownership_controls: None,
+            acl: None,
+            tags: Vec::new(),
+            accelerate: None,
+            request_payment: None,
+            intelligent_tiering: Vec::new(),
+            inventory: Vec::new(),
+            analytics: Vec::new(),
+            metrics: Vec::new(),
         }
     }
 
+    pub fn with_region(mut self, region: String) -> Self {
+        self.region = Some(region);
+        self
+    }
+
     pub fn with_policy(mut self, policy: String) -> Self {
         self.policy = Some(policy);
         self
@@ -103,14 +1027,108 @@ impl BucketBuilder {
         self
     }
 
+    pub fn with_lifecycle(mut self, lifecycle: BucketLifecycle) -> Self {
+        self.lifecycle = Some(lifecycle);
+        self
+    }
+
+    pub fn with_replication(mut self, replication: BucketReplication) -> Self {
+        self.replication = Some(replication);
+        self
+    }
+
+    pub fn with_cors(mut self, cors: BucketCors) -> Self {
+        self.cors = Some(cors);
+        self
+    }
+
+    pub fn with_website(mut self, website: BucketWebsite) -> Self {
+        self.website = Some(website);
+        self
+    }
+
+    pub fn with_notification(mut self, notification: BucketNotification) -> Self {
+        self.notification = Some(notification);
+        self
+    }
+
+    pub fn with_object_lock(mut self, object_lock: BucketObjectLock) -> Self {
+        self.object_lock = Some(object_lock);
+        self
+    }
+
+    pub fn with_ownership_controls(mut self, ownership_controls: BucketOwnershipControls) -> Self {
+        self.ownership_controls = Some(ownership_controls);
+        self
+    }
+
+    pub fn with_acl(mut self, acl: BucketAcl) -> Self {
+        self.acl = Some(acl);
+        self
+    }
+
+    pub fn with_tags(mut self, tags: Vec<(String, String)>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    pub fn with_accelerate(mut self, accelerate: BucketAccelerate) -> Self {
+        self.accelerate = Some(accelerate);
+        self
+    }
+
+    pub fn with_request_payment(mut self, request_payment: BucketRequestPayment) -> Self {
+        self.request_payment = Some(request_payment);
+        self
+    }
+
+    pub fn with_intelligent_tiering(
+        mut self,
+        intelligent_tiering: Vec<IntelligentTieringConfiguration>,
+    ) -> Self {
+        self.intelligent_tiering = intelligent_tiering;
+        self
+    }
+
+    pub fn with_inventory(mut self, inventory: Vec<InventoryConfiguration>) -> Self {
+        self.inventory = inventory;
+        self
+    }
+
+    pub fn with_analytics(mut self, analytics: Vec<AnalyticsConfiguration>) -> Self {
+        self.analytics = analytics;
+        self
+    }
+
+    pub fn with_metrics(mut self, metrics: Vec<MetricsConfiguration>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
     pub fn build(self) -> Bucket {
         Bucket {
             name: self.name,
+            region: self.region,
             policy: self.policy,
             public_access_block: self.public_access_block,
             encryption: self.encryption,
             versioning: self.versioning,
             logging: self.logging,
+            lifecycle: self.lifecycle,
+            replication: self.replication,
+            cors: self.cors,
+            website: self.website,
+            notification: self.notification,
+            object_lock: self.object_lock,
+            ownership_controls: self.ownership_controls,
+            acl: self.acl,
+            tags: self.tags,
+            accelerate: self.accelerate,
+            request_payment: self.request_payment,
+            intelligent_tiering: self.intelligent_tiering,
+            inventory: self.inventory,
+            analytics: self.analytics,
+            metrics: self.metrics,
         }
     }
 }
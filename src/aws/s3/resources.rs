@@ -0,0 +1,1054 @@
+use crate::{
+    aws::s3::{
+        AccessPoint, Bucket, BucketAcl, BucketOwnershipControls, BucketPublicAccessBlock,
+        BucketRequestPayment, BucketWebsite, ConfigurationFilter, CorsRule, LifecycleRule,
+        LifecycleRuleFilter, MultiRegionAccessPoint, NotificationTarget, ReplicationDestination,
+        ReplicationRule, S3Resource,
+    },
+    core::{
+        IntoResources,
+        ir::{Attributes, Lookup, Resource, Value},
+    },
+    terraform::normalize_resource_name,
+};
+
+impl IntoResources for S3Resource {
+    fn into_resources(self) -> Vec<Resource> {
+        match self {
+            S3Resource::Bucket(bucket) => bucket.into_resources(),
+            S3Resource::AccessPoint(access_point) => access_point.into_resources(),
+            S3Resource::MultiRegionAccessPoint(access_point) => access_point.into_resources(),
+        }
+    }
+}
+
+impl IntoResources for Bucket {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        // Most S3 bucket sub-resources are imported using the bucket name alone.
+        let mut bucket = Resource::new("aws_s3_bucket", &resource_name, &self.name)
+            .with_identifier("id", &self.name)
+            .with_identifier("arn", format!("arn:aws:s3:::{}", self.name))
+            .with("bucket", &self.name)
+            .with_optional(
+                "object_lock_enabled",
+                self.object_lock
+                    .as_ref()
+                    .filter(|object_lock| object_lock.enabled)
+                    .map(|_| true),
+            );
+
+        // Tags with the reserved `aws:` prefix are managed by AWS services and cannot be
+        // set by users.
+        let tags: Vec<_> = self
+            .tags
+            .into_iter()
+            .filter(|(key, _)| !key.starts_with("aws:"))
+            .collect();
+
+        if !tags.is_empty() {
+            bucket = bucket.with("tags", Value::Map(tags));
+        }
+
+        let bucket_reference = bucket.reference("bucket", &self.name);
+
+        let mut resources = vec![bucket];
+
+        if let Some(public_access_block) = self.public_access_block {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_public_access_block",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with("block_public_acls", public_access_block.block_public_acls)
+                .with(
+                    "block_public_policy",
+                    public_access_block.block_public_policy,
+                )
+                .with("ignore_public_acls", public_access_block.ignore_public_acls)
+                .with(
+                    "restrict_public_buckets",
+                    public_access_block.restrict_public_buckets,
+                ),
+            );
+        }
+
+        if let Some(encryption) = self.encryption {
+            let sse_by_default = Attributes::new()
+                .with("sse_algorithm", encryption.sse_algorithm)
+                .with_optional(
+                    "kms_master_key_id",
+                    encryption
+                        .kms_master_key_id
+                        .map(|key| Lookup::new(&["aws_kms_key", "aws_kms_alias"], key)),
+                );
+
+            let mut rule = Attributes::new().with(
+                "apply_server_side_encryption_by_default",
+                Value::Block(sse_by_default),
+            );
+
+            if encryption.bucket_key_enabled {
+                rule.insert("bucket_key_enabled", true);
+            }
+
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_server_side_encryption_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with("rule", Value::Blocks(vec![rule])),
+            );
+        }
+
+        if let Some(versioning) = self.versioning {
+            resources.push(
+                Resource::new("aws_s3_bucket_versioning", &resource_name, &self.name)
+                    .with("bucket", bucket_reference.clone())
+                    .with(
+                        "versioning_configuration",
+                        Value::Block(Attributes::new().with("status", versioning.status)),
+                    ),
+            );
+        }
+
+        if let Some(logging) = self.logging {
+            resources.push(
+                Resource::new("aws_s3_bucket_logging", &resource_name, &self.name)
+                    .with("bucket", bucket_reference.clone())
+                    .with(
+                        "target_bucket",
+                        Lookup::new(&["aws_s3_bucket"], logging.target_bucket),
+                    )
+                    .with("target_prefix", logging.target_prefix),
+            );
+        }
+
+        if let Some(lifecycle) = self.lifecycle {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_lifecycle_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with(
+                    "rule",
+                    Value::Blocks(lifecycle.rules.into_iter().map(lifecycle_rule).collect()),
+                )
+                .with_optional(
+                    "transition_default_minimum_object_size",
+                    lifecycle.transition_default_minimum_object_size,
+                ),
+            );
+        }
+
+        if let Some(replication) = self.replication {
+            let mut replication_configuration = Resource::new(
+                "aws_s3_bucket_replication_configuration",
+                &resource_name,
+                &self.name,
+            )
+            .with("bucket", bucket_reference.clone())
+            .with("role", Lookup::new(&["aws_iam_role"], replication.role))
+            .with(
+                "rule",
+                Value::Blocks(
+                    replication
+                        .rules
+                        .into_iter()
+                        .map(replication_rule)
+                        .collect(),
+                ),
+            );
+
+            // S3 rejects replication configuration until versioning is enabled, which
+            // nothing in the configuration itself tells Terraform.
+            if let Some(versioning) = resources
+                .iter()
+                .find(|resource| resource.resource_type == "aws_s3_bucket_versioning")
+            {
+                replication_configuration = replication_configuration.with_dependency(versioning);
+            }
+
+            resources.push(replication_configuration);
+        }
+
+        if let Some(cors) = self.cors {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_cors_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with(
+                    "cors_rule",
+                    Value::Blocks(cors.rules.into_iter().map(cors_rule).collect()),
+                ),
+            );
+        }
+
+        if let Some(website) = self.website {
+            resources.push(website_configuration(
+                website,
+                Resource::new(
+                    "aws_s3_bucket_website_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone()),
+            ));
+        }
+
+        if let Some(notification) = self.notification {
+            let mut resource =
+                Resource::new("aws_s3_bucket_notification", &resource_name, &self.name)
+                    .with("bucket", bucket_reference.clone());
+
+            if notification.eventbridge {
+                resource = resource.with("eventbridge", true);
+            }
+
+            for (block, arn_attribute, resource_type, targets) in [
+                ("topic", "topic_arn", "aws_sns_topic", notification.topics),
+                ("queue", "queue_arn", "aws_sqs_queue", notification.queues),
+                (
+                    "lambda_function",
+                    "lambda_function_arn",
+                    "aws_lambda_function",
+                    notification.lambda_functions,
+                ),
+            ] {
+                if !targets.is_empty() {
+                    resource = resource.with(
+                        block,
+                        Value::Blocks(
+                            targets
+                                .into_iter()
+                                .map(|target| {
+                                    notification_target(target, arn_attribute, resource_type)
+                                })
+                                .collect(),
+                        ),
+                    );
+                }
+            }
+
+            resources.push(resource);
+        }
+
+        if let Some(retention) = self
+            .object_lock
+            .and_then(|object_lock| object_lock.default_retention)
+        {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_object_lock_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with(
+                    "rule",
+                    Value::Block(
+                        Attributes::new().with(
+                            "default_retention",
+                            Value::Block(
+                                Attributes::new()
+                                    .with_optional("mode", retention.mode)
+                                    .with_optional("days", retention.days)
+                                    .with_optional("years", retention.years),
+                            ),
+                        ),
+                    ),
+                ),
+            );
+        }
+
+        // Buckets that enforce bucket owner ownership have ACLs disabled, and the provider
+        // refuses to manage an ACL for them.
+        let acls_enabled = self.ownership_controls.as_ref().is_none_or(|controls| {
+            controls.object_ownership != BucketOwnershipControls::BUCKET_OWNER_ENFORCED
+        });
+
+        if let Some(ownership_controls) = self.ownership_controls {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_ownership_controls",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with(
+                    "rule",
+                    Value::Block(
+                        Attributes::new()
+                            .with("object_ownership", ownership_controls.object_ownership),
+                    ),
+                ),
+            );
+        }
+
+        if let Some(acl) = self.acl.filter(|_| acls_enabled) {
+            let mut resource = if acl.is_private() {
+                Resource::new(
+                    "aws_s3_bucket_acl",
+                    &resource_name,
+                    format!("{},private", self.name),
+                )
+                .with("bucket", bucket_reference.clone())
+                .with("acl", "private")
+            } else {
+                Resource::new("aws_s3_bucket_acl", &resource_name, &self.name)
+                    .with("bucket", bucket_reference.clone())
+                    .with(
+                        "access_control_policy",
+                        Value::Block(access_control_policy(acl)),
+                    )
+            };
+
+            // Setting an ACL fails while ownership controls still disable ACLs.
+            if let Some(ownership_controls) = resources
+                .iter()
+                .find(|resource| resource.resource_type == "aws_s3_bucket_ownership_controls")
+            {
+                resource = resource.with_dependency(ownership_controls);
+            }
+
+            resources.push(resource);
+        }
+
+        if let Some(accelerate) = self.accelerate {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_accelerate_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with("status", accelerate.status),
+            );
+        }
+
+        // Every bucket reports a payer, so only buckets that deviate from the default are
+        // worth managing.
+        if let Some(request_payment) = self
+            .request_payment
+            .filter(|payment| payment.payer != BucketRequestPayment::BUCKET_OWNER)
+        {
+            resources.push(
+                Resource::new(
+                    "aws_s3_bucket_request_payment_configuration",
+                    &resource_name,
+                    &self.name,
+                )
+                .with("bucket", bucket_reference.clone())
+                .with("payer", request_payment.payer),
+            );
+        }
+
+        // Buckets can hold several configurations of the following kinds, each of which is
+        // named after its ID and imported as `<bucket>:<id>`.
+        let configuration_resource = |resource_type: &str, id: &str| {
+            Resource::new(
+                resource_type,
+                format!("{}_{}", resource_name, normalize_resource_name(id)),
+                format!("{}:{}", self.name, id),
+            )
+            .with_group(&resource_name)
+            .with("bucket", bucket_reference.clone())
+            .with("name", id)
+        };
+
+        for configuration in self.intelligent_tiering {
+            let mut resource = configuration_resource(
+                "aws_s3_bucket_intelligent_tiering_configuration",
+                &configuration.id,
+            )
+            .with("status", configuration.status);
+
+            if configuration.prefix.is_some() || !configuration.tags.is_empty() {
+                let mut filter = Attributes::new().with_optional("prefix", configuration.prefix);
+
+                if !configuration.tags.is_empty() {
+                    filter.insert("tags", Value::Map(configuration.tags));
+                }
+
+                resource = resource.with("filter", Value::Block(filter));
+            }
+
+            resources.push(
+                resource.with(
+                    "tiering",
+                    Value::Blocks(
+                        configuration
+                            .tierings
+                            .into_iter()
+                            .map(|tiering| {
+                                Attributes::new()
+                                    .with("access_tier", tiering.access_tier)
+                                    .with("days", tiering.days)
+                            })
+                            .collect(),
+                    ),
+                ),
+            );
+        }
+
+        for configuration in self.inventory {
+            let mut resource = configuration_resource("aws_s3_bucket_inventory", &configuration.id)
+                .with("enabled", configuration.enabled)
+                .with(
+                    "included_object_versions",
+                    configuration.included_object_versions,
+                );
+
+            if !configuration.optional_fields.is_empty() {
+                resource = resource.with("optional_fields", configuration.optional_fields);
+            }
+
+            if let Some(frequency) = configuration.frequency {
+                resource = resource.with(
+                    "schedule",
+                    Value::Block(Attributes::new().with("frequency", frequency)),
+                );
+            }
+
+            if let Some(prefix) = configuration.prefix {
+                resource = resource.with(
+                    "filter",
+                    Value::Block(Attributes::new().with("prefix", prefix)),
+                );
+            }
+
+            if let Some(destination) = configuration.destination {
+                let mut bucket_destination = Attributes::new()
+                    .with(
+                        "bucket_arn",
+                        Lookup::new(&["aws_s3_bucket"], destination.bucket_arn),
+                    )
+                    .with("format", destination.format)
+                    .with_optional("account_id", destination.account_id)
+                    .with_optional("prefix", destination.prefix);
+
+                if let Some(key_id) = destination.sse_kms_key_id {
+                    bucket_destination.insert(
+                        "encryption",
+                        Value::Block(
+                            Attributes::new().with(
+                                "sse_kms",
+                                Value::Block(
+                                    Attributes::new()
+                                        .with("key_id", Lookup::new(&["aws_kms_key"], key_id)),
+                                ),
+                            ),
+                        ),
+                    );
+                } else if destination.sse_s3 {
+                    bucket_destination.insert(
+                        "encryption",
+                        Value::Block(
+                            Attributes::new().with("sse_s3", Value::Block(Attributes::new())),
+                        ),
+                    );
+                }
+
+                resource = resource.with(
+                    "destination",
+                    Value::Block(
+                        Attributes::new().with("bucket", Value::Block(bucket_destination)),
+                    ),
+                );
+            }
+
+            resources.push(resource);
+        }
+
+        for configuration in self.analytics {
+            let mut resource =
+                configuration_resource("aws_s3_bucket_analytics_configuration", &configuration.id);
+
+            if let Some(filter) = configuration.filter {
+                resource = resource.with("filter", Value::Block(configuration_filter(filter)));
+            }
+
+            // The provider can only express storage class analysis that exports its results.
+            if let Some(data_export) = configuration.data_export {
+                let destination = Attributes::new()
+                    .with(
+                        "bucket_arn",
+                        Lookup::new(&["aws_s3_bucket"], data_export.bucket_arn),
+                    )
+                    .with_optional("bucket_account_id", data_export.bucket_account_id)
+                    .with("format", data_export.format)
+                    .with_optional("prefix", data_export.prefix);
+
+                resource = resource.with(
+                    "storage_class_analysis",
+                    Value::Block(
+                        Attributes::new().with(
+                            "data_export",
+                            Value::Block(
+                                Attributes::new()
+                                    .with(
+                                        "output_schema_version",
+                                        data_export.output_schema_version,
+                                    )
+                                    .with(
+                                        "destination",
+                                        Value::Block(Attributes::new().with(
+                                            "s3_bucket_destination",
+                                            Value::Block(destination),
+                                        )),
+                                    ),
+                            ),
+                        ),
+                    ),
+                );
+            }
+
+            resources.push(resource);
+        }
+
+        for configuration in self.metrics {
+            let mut resource = configuration_resource("aws_s3_bucket_metric", &configuration.id);
+
+            if let Some(filter) = configuration.filter {
+                resource = resource.with("filter", Value::Block(configuration_filter(filter)));
+            }
+
+            resources.push(resource);
+        }
+
+        if let Some(policy) = self.policy {
+            let policy_json: serde_json::Value = serde_json::from_str(&policy).unwrap();
+
+            resources.push(
+                Resource::new("aws_s3_bucket_policy", &resource_name, &self.name)
+                    .with("bucket", bucket_reference)
+                    .with("policy", policy_json),
+            );
+        }
+
+        match self.region {
+            Some(region) => resources
+                .into_iter()
+                .map(|resource| resource.with_region(&region))
+                .collect(),
+            None => resources,
+        }
+    }
+}
+
+impl IntoResources for AccessPoint {
+    fn into_resources(self) -> Vec<Resource> {
+        let resource_name = normalize_resource_name(&self.name);
+
+        // Access points are written alongside the bucket they front.
+        let mut access_point = Resource::new(
+            "aws_s3_access_point",
+            &resource_name,
+            format!("{}:{}", self.account_id, self.name),
+        )
+        .with_group(normalize_resource_name(&self.bucket))
+        .with_identifier("arn", &self.arn)
+        .with("bucket", Lookup::new(&["aws_s3_bucket"], &self.bucket))
+        .with("name", &self.name)
+        .with_optional(
+            "bucket_account_id",
+            self.bucket_account_id
+                .filter(|account_id| *account_id != self.account_id),
+        );
+
+        if let Some(public_access_block) = self.public_access_block {
+            access_point = access_point.with(
+                "public_access_block_configuration",
+                Value::Block(public_access_block_configuration(public_access_block)),
+            );
+        }
+
+        if let Some(vpc_id) = self.vpc_id {
+            access_point = access_point.with(
+                "vpc_configuration",
+                Value::Block(Attributes::new().with("vpc_id", Lookup::new(&["aws_vpc"], vpc_id))),
+            );
+        }
+
+        let access_point_reference = access_point.reference("arn", &self.arn);
+        let group = normalize_resource_name(&self.bucket);
+
+        let mut resources = vec![access_point];
+
+        if let Some(policy) = self.policy {
+            let policy_json: serde_json::Value = serde_json::from_str(&policy).unwrap();
+
+            resources.push(
+                Resource::new(
+                    "aws_s3control_access_point_policy",
+                    &resource_name,
+                    &self.arn,
+                )
+                .with_group(group)
+                .with("access_point_arn", access_point_reference)
+                .with("policy", policy_json),
+            );
+        }
+
+        resources
+    }
+}
+
+impl IntoResources for MultiRegionAccessPoint {
+    fn into_resources(self) -> Vec<Resource> {
+        let mut details = Attributes::new().with("name", &self.name);
+
+        if let Some(public_access_block) = self.public_access_block {
+            details.insert(
+                "public_access_block",
+                Value::Block(public_access_block_configuration(public_access_block)),
+            );
+        }
+
+        let regions = self
+            .regions
+            .into_iter()
+            .map(|region| {
+                Attributes::new()
+                    .with("bucket", Lookup::new(&["aws_s3_bucket"], region.bucket))
+                    .with_optional(
+                        "bucket_account_id",
+                        region
+                            .bucket_account_id
+                            .filter(|account_id| *account_id != self.account_id),
+                    )
+            })
+            .collect();
+
+        details.insert("region", Value::Blocks(regions));
+
+        vec![
+            Resource::new(
+                "aws_s3control_multi_region_access_point",
+                normalize_resource_name(&self.name),
+                format!("{}:{}", self.account_id, self.name),
+            )
+            .with("details", Value::Block(details)),
+        ]
+    }
+}
+
+fn public_access_block_configuration(public_access_block: BucketPublicAccessBlock) -> Attributes {
+    Attributes::new()
+        .with("block_public_acls", public_access_block.block_public_acls)
+        .with(
+            "block_public_policy",
+            public_access_block.block_public_policy,
+        )
+        .with("ignore_public_acls", public_access_block.ignore_public_acls)
+        .with(
+            "restrict_public_buckets",
+            public_access_block.restrict_public_buckets,
+        )
+}
+
+fn lifecycle_rule(rule: LifecycleRule) -> Attributes {
+    let mut attributes = Attributes::new()
+        .with("id", rule.id)
+        .with("status", rule.status)
+        // Rules without a filter apply to every object, which the provider expects to be
+        // spelled out as an empty filter.
+        .with(
+            "filter",
+            Value::Block(rule.filter.map(lifecycle_filter).unwrap_or_default()),
+        );
+
+    if let Some(expiration) = rule.expiration {
+        attributes.insert(
+            "expiration",
+            Value::Block(
+                Attributes::new()
+                    .with_optional("date", expiration.date)
+                    .with_optional("days", expiration.days)
+                    .with_optional(
+                        "expired_object_delete_marker",
+                        expiration.expired_object_delete_marker,
+                    ),
+            ),
+        );
+    }
+
+    if !rule.transitions.is_empty() {
+        attributes.insert(
+            "transition",
+            Value::Blocks(
+                rule.transitions
+                    .into_iter()
+                    .map(|transition| {
+                        Attributes::new()
+                            .with_optional("date", transition.date)
+                            .with_optional("days", transition.days)
+                            .with("storage_class", transition.storage_class)
+                    })
+                    .collect(),
+            ),
+        );
+    }
+
+    if let Some(expiration) = rule.noncurrent_version_expiration {
+        attributes.insert(
+            "noncurrent_version_expiration",
+            Value::Block(
+                Attributes::new()
+                    .with_optional("noncurrent_days", expiration.noncurrent_days)
+                    .with_optional(
+                        "newer_noncurrent_versions",
+                        expiration.newer_noncurrent_versions,
+                    ),
+            ),
+        );
+    }
+
+    if !rule.noncurrent_version_transitions.is_empty() {
+        attributes.insert(
+            "noncurrent_version_transition",
+            Value::Blocks(
+                rule.noncurrent_version_transitions
+                    .into_iter()
+                    .map(|transition| {
+                        Attributes::new()
+                            .with_optional("noncurrent_days", transition.noncurrent_days)
+                            .with_optional(
+                                "newer_noncurrent_versions",
+                                transition.newer_noncurrent_versions,
+                            )
+                            .with("storage_class", transition.storage_class)
+                    })
+                    .collect(),
+            ),
+        );
+    }
+
+    if let Some(days) = rule.abort_incomplete_multipart_upload_days {
+        attributes.insert(
+            "abort_incomplete_multipart_upload",
+            Value::Block(Attributes::new().with("days_after_initiation", days)),
+        );
+    }
+
+    attributes
+}
+
+fn lifecycle_filter(filter: LifecycleRuleFilter) -> Attributes {
+    let mut attributes = Attributes::new()
+        .with_optional("prefix", filter.prefix)
+        .with_optional("object_size_greater_than", filter.object_size_greater_than)
+        .with_optional("object_size_less_than", filter.object_size_less_than);
+
+    if let Some((key, value)) = filter.tag {
+        attributes.insert(
+            "tag",
+            Value::Block(Attributes::new().with("key", key).with("value", value)),
+        );
+    }
+
+    if let Some(and) = filter.and {
+        let mut and_attributes = Attributes::new()
+            .with_optional("prefix", and.prefix)
+            .with_optional("object_size_greater_than", and.object_size_greater_than)
+            .with_optional("object_size_less_than", and.object_size_less_than);
+
+        if !and.tags.is_empty() {
+            and_attributes.insert("tags", Value::Map(and.tags));
+        }
+
+        attributes.insert("and", Value::Block(and_attributes));
+    }
+
+    attributes
+}
+
+fn replication_rule(rule: ReplicationRule) -> Attributes {
+    let mut attributes = Attributes::new()
+        .with_optional("id", rule.id)
+        .with_optional("priority", rule.priority)
+        .with("status", rule.status)
+        .with_optional("prefix", rule.prefix);
+
+    if let Some(filter) = rule.filter {
+        let mut filter_attributes = Attributes::new().with_optional("prefix", filter.prefix);
+
+        if let Some((key, value)) = filter.tag {
+            filter_attributes.insert(
+                "tag",
+                Value::Block(Attributes::new().with("key", key).with("value", value)),
+            );
+        }
+
+        if let Some(and) = filter.and {
+            let mut and_attributes = Attributes::new().with_optional("prefix", and.prefix);
+
+            if !and.tags.is_empty() {
+                and_attributes.insert("tags", Value::Map(and.tags));
+            }
+
+            filter_attributes.insert("and", Value::Block(and_attributes));
+        }
+
+        attributes.insert("filter", Value::Block(filter_attributes));
+    }
+
+    if let Some(destination) = rule.destination {
+        attributes.insert(
+            "destination",
+            Value::Block(replication_destination(destination)),
+        );
+    }
+
+    if let Some(status) = rule.delete_marker_replication_status {
+        attributes.insert(
+            "delete_marker_replication",
+            Value::Block(Attributes::new().with("status", status)),
+        );
+    }
+
+    if let Some(status) = rule.existing_object_replication_status {
+        attributes.insert(
+            "existing_object_replication",
+            Value::Block(Attributes::new().with("status", status)),
+        );
+    }
+
+    if rule.replica_modifications_status.is_some()
+        || rule.sse_kms_encrypted_objects_status.is_some()
+    {
+        let mut criteria = Attributes::new();
+
+        if let Some(status) = rule.replica_modifications_status {
+            criteria.insert(
+                "replica_modifications",
+                Value::Block(Attributes::new().with("status", status)),
+            );
+        }
+
+        if let Some(status) = rule.sse_kms_encrypted_objects_status {
+            criteria.insert(
+                "sse_kms_encrypted_objects",
+                Value::Block(Attributes::new().with("status", status)),
+            );
+        }
+
+        attributes.insert("source_selection_criteria", Value::Block(criteria));
+    }
+
+    attributes
+}
+
+fn replication_destination(destination: ReplicationDestination) -> Attributes {
+    let mut attributes = Attributes::new()
+        .with(
+            "bucket",
+            Lookup::new(&["aws_s3_bucket"], destination.bucket),
+        )
+        .with_optional("account", destination.account)
+        .with_optional("storage_class", destination.storage_class);
+
+    if let Some(owner) = destination.access_control_translation_owner {
+        attributes.insert(
+            "access_control_translation",
+            Value::Block(Attributes::new().with("owner", owner)),
+        );
+    }
+
+    if let Some(replica_kms_key_id) = destination.replica_kms_key_id {
+        attributes.insert(
+            "encryption_configuration",
+            Value::Block(Attributes::new().with(
+                "replica_kms_key_id",
+                Lookup::new(&["aws_kms_key"], replica_kms_key_id),
+            )),
+        );
+    }
+
+    if let Some(replication_time) = destination.replication_time {
+        let mut time = Attributes::new().with("status", replication_time.status);
+
+        if let Some(minutes) = replication_time.minutes {
+            time.insert(
+                "time",
+                Value::Block(Attributes::new().with("minutes", minutes)),
+            );
+        }
+
+        attributes.insert("replication_time", Value::Block(time));
+    }
+
+    if let Some(metrics) = destination.metrics {
+        let mut metrics_attributes = Attributes::new().with("status", metrics.status);
+
+        if let Some(minutes) = metrics.event_threshold_minutes {
+            metrics_attributes.insert(
+                "event_threshold",
+                Value::Block(Attributes::new().with("minutes", minutes)),
+            );
+        }
+
+        attributes.insert("metrics", Value::Block(metrics_attributes));
+    }
+
+    attributes
+}
+
+fn cors_rule(rule: CorsRule) -> Attributes {
+    let mut attributes = Attributes::new().with_optional("id", rule.id);
+
+    if !rule.allowed_headers.is_empty() {
+        attributes.insert("allowed_headers", rule.allowed_headers);
+    }
+
+    attributes.insert("allowed_methods", rule.allowed_methods);
+    attributes.insert("allowed_origins", rule.allowed_origins);
+
+    if !rule.expose_headers.is_empty() {
+        attributes.insert("expose_headers", rule.expose_headers);
+    }
+
+    attributes.with_optional("max_age_seconds", rule.max_age_seconds)
+}
+
+fn website_configuration(website: BucketWebsite, mut resource: Resource) -> Resource {
+    if let Some(suffix) = website.index_document {
+        resource = resource.with(
+            "index_document",
+            Value::Block(Attributes::new().with("suffix", suffix)),
+        );
+    }
+
+    if let Some(key) = website.error_document {
+        resource = resource.with(
+            "error_document",
+            Value::Block(Attributes::new().with("key", key)),
+        );
+    }
+
+    if let Some(redirect) = website.redirect_all_requests_to {
+        resource = resource.with(
+            "redirect_all_requests_to",
+            Value::Block(
+                Attributes::new()
+                    .with("host_name", redirect.host_name)
+                    .with_optional("protocol", redirect.protocol),
+            ),
+        );
+    }
+
+    if !website.routing_rules.is_empty() {
+        let routing_rules = website
+            .routing_rules
+            .into_iter()
+            .map(|rule| {
+                let mut attributes = Attributes::new();
+
+                if let Some(condition) = rule.condition {
+                    attributes.insert(
+                        "condition",
+                        Value::Block(
+                            Attributes::new()
+                                .with_optional(
+                                    "http_error_code_returned_equals",
+                                    condition.http_error_code_returned_equals,
+                                )
+                                .with_optional("key_prefix_equals", condition.key_prefix_equals),
+                        ),
+                    );
+                }
+
+                if let Some(redirect) = rule.redirect {
+                    attributes.insert(
+                        "redirect",
+                        Value::Block(
+                            Attributes::new()
+                                .with_optional("host_name", redirect.host_name)
+                                .with_optional("http_redirect_code", redirect.http_redirect_code)
+                                .with_optional("protocol", redirect.protocol)
+                                .with_optional(
+                                    "replace_key_prefix_with",
+                                    redirect.replace_key_prefix_with,
+                                )
+                                .with_optional("replace_key_with", redirect.replace_key_with),
+                        ),
+                    );
+                }
+
+                attributes
+            })
+            .collect();
+
+        resource = resource.with("routing_rule", Value::Blocks(routing_rules));
+    }
+
+    resource
+}
+
+fn notification_target(
+    target: NotificationTarget,
+    arn_attribute: &str,
+    resource_type: &str,
+) -> Attributes {
+    Attributes::new()
+        .with_optional("id", target.id)
+        .with(arn_attribute, Lookup::new(&[resource_type], target.arn))
+        .with("events", target.events)
+        .with_optional("filter_prefix", target.filter_prefix)
+        .with_optional("filter_suffix", target.filter_suffix)
+}
+
+fn access_control_policy(acl: BucketAcl) -> Attributes {
+    let grants = acl
+        .grants
+        .into_iter()
+        .map(|grant| {
+            Attributes::new()
+                .with(
+                    "grantee",
+                    Value::Block(
+                        Attributes::new()
+                            .with("type", grant.grantee_type)
+                            .with_optional("id", grant.grantee_id)
+                            .with_optional("uri", grant.grantee_uri)
+                            .with_optional("email_address", grant.grantee_email_address),
+                    ),
+                )
+                .with("permission", grant.permission)
+        })
+        .collect();
+
+    Attributes::new().with("grant", Value::Blocks(grants)).with(
+        "owner",
+        Value::Block(
+            Attributes::new()
+                .with_optional("id", acl.owner_id)
+                .with_optional("display_name", acl.owner_display_name),
+        ),
+    )
+}
+
+fn configuration_filter(filter: ConfigurationFilter) -> Attributes {
+    let mut attributes = Attributes::new().with_optional("prefix", filter.prefix);
+
+    if !filter.tags.is_empty() {
+        attributes.insert("tags", Value::Map(filter.tags));
+    }
+
+    attributes.with_optional(
+        "access_point",
+        filter
+            .access_point_arn
+            .map(|arn| Lookup::new(&["aws_s3_access_point"], arn)),
+    )
+}
@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
-use clap::{Parser, Subcommand};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+
+use crate::{core::Layout, terraform::DEFAULT_AWS_PROVIDER_VERSION};
 
 #[derive(Parser)]
 pub struct Args {
@@ -12,18 +14,139 @@ pub struct Args {
     #[clap(short, long, global = true)]
     pub profile: Option<String>,
 
-    /// Output path to write Terraform configuration to (default: stdout)
+    /// Output path to write generated configuration to (default: stdout)
     #[clap(short, long, global = true)]
     pub output: Option<PathBuf>,
 
+    /// Directory to write generated configuration to, split into multiple files
+    #[clap(long, global = true, conflicts_with = "output")]
+    pub output_dir: Option<PathBuf>,
+
+    /// How to split generated configuration into files when writing to a directory
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t,
+        requires = "output_dir"
+    )]
+    pub layout: Layout,
+
+    /// Format to render the generated configuration in
+    #[clap(short, long, global = true, value_enum, default_value_t)]
+    pub format: Format,
+
+    /// Do not generate `import` blocks for the generated resources
+    #[clap(long = "no-import-blocks", global = true, action = ArgAction::SetFalse)]
+    pub import_blocks: bool,
+
+    /// Version constraint for the AWS provider in generated Terraform configuration
+    #[clap(long, global = true, default_value = DEFAULT_AWS_PROVIDER_VERSION)]
+    pub provider_version: String,
+
+    /// Terraform backend to store state in
+    #[clap(long, global = true, value_enum)]
+    pub backend: Option<StateBackendKind>,
+
+    /// Bucket to store state in with the `s3` backend
+    #[clap(long, global = true, required_if_eq("backend", "s3"))]
+    pub backend_bucket: Option<String>,
+
+    /// Key to store state at with the `s3` backend
+    #[clap(long, global = true, required_if_eq("backend", "s3"))]
+    pub backend_key: Option<String>,
+
+    /// Region of the state bucket with the `s3` backend (default: the provider region)
+    #[clap(long, global = true)]
+    pub backend_region: Option<String>,
+
+    /// Path to store state at with the `local` backend
+    #[clap(long, global = true)]
+    pub backend_path: Option<String>,
+
+    /// Path to write a shell script of `terraform import` commands to
+    #[clap(long, global = true)]
+    pub import_script: Option<PathBuf>,
+
+    /// Path to write a Terraform state file (format version 4) for the generated resources to
+    #[clap(long, global = true)]
+    pub state: Option<PathBuf>,
+
+    /// Path to write a CloudFormation resources-to-import file to
+    #[clap(long, global = true)]
+    pub resources_to_import: Option<PathBuf>,
+
+    /// Fail instead of writing output when any setting could not be fetched, e.g. because
+    /// access was denied
+    #[clap(long, global = true)]
+    pub strict: bool,
+
     #[clap(subcommand)]
     pub command: Command,
 }
 
+/// The format generated configuration is rendered in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Terraform native syntax (`.tf`)
+    #[default]
+    Hcl,
+    /// Terraform JSON syntax (`.tf.json`)
+    Json,
+    /// CloudFormation template in YAML
+    CloudformationYaml,
+    /// CloudFormation template in JSON
+    CloudformationJson,
+    /// Pulumi YAML program (`Pulumi.yaml`)
+    PulumiYaml,
+}
+
+impl Format {
+    pub fn is_terraform(&self) -> bool {
+        matches!(self, Format::Hcl | Format::Json)
+    }
+
+    pub fn is_cloudformation(&self) -> bool {
+        matches!(
+            self,
+            Format::CloudformationYaml | Format::CloudformationJson
+        )
+    }
+}
+
+/// The Terraform backend generated configuration stores state in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StateBackendKind {
+    /// Amazon S3 (`backend "s3"`)
+    S3,
+    /// The local filesystem (`backend "local"`)
+    Local,
+}
+
 #[derive(Subcommand)]
 pub enum Command {
-    /// Generate Terraform configuration for S3 buckets
+    /// Generate Terraform configuration for S3 buckets and access points
     S3,
+    /// Generate Terraform configuration for IAM roles, customer-managed policies and
+    /// instance profiles
+    Iam,
+    /// Generate Terraform configuration for IAM users, groups and OIDC/SAML identity
+    /// providers. Access keys, login profiles and other credentials are never generated
+    IamIdentities,
+    /// Generate Terraform configuration for security groups, their rules and the prefix
+    /// lists those refer to
+    SecurityGroups,
+    /// Generate Terraform configuration for Lambda functions, their aliases, permissions
+    /// and event source mappings. Function code is never fetched
+    Lambda {
+        /// Supply environment variable values through a sensitive input variable per
+        /// function rather than writing them into the configuration
+        #[clap(long)]
+        environment_as_variables: bool,
+    },
+    /// Generate Terraform configuration for VPC networking: VPCs, subnets, route tables,
+    /// gateways, Elastic IPs, VPC endpoints and DHCP option sets
+    Vpc,
 }
 
 pub fn parse() -> Args {
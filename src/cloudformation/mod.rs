@@ -0,0 +1,96 @@
+use serde_json::{Value as JsonValue, json};
+use tracing::warn;
+
+use crate::core::ir;
+
+pub mod s3;
+pub mod template;
+
+pub use template::{Resource, ResourcesToImportBackend, TemplateBackend, TemplateFormat};
+
+/// Converts a resource name into a CloudFormation logical ID, which may only contain
+/// alphanumeric characters, e.g. `my-bucket.logs` becomes `MyBucketLogs`.
+pub fn normalize_logical_id(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts resources into their CloudFormation equivalents, skipping any resource
+/// types CloudFormation output does not support.
+pub fn to_resources(resources: &[ir::Resource]) -> Vec<Resource> {
+    // A stack can only hold resources in its own region.
+    let (resources, other_regions): (Vec<_>, Vec<_>) = resources
+        .iter()
+        .cloned()
+        .partition(|resource| resource.region.is_none());
+
+    for resource in other_regions {
+        warn!(
+            "Skipping {}: resource is in {}, outside the stack's region",
+            resource.address(),
+            resource.region.unwrap_or_default()
+        );
+    }
+
+    let (supported, unsupported): (Vec<_>, Vec<_>) = resources
+        .into_iter()
+        .partition(|resource| s3::supports(&resource.resource_type));
+
+    for resource in unsupported {
+        warn!(
+            "Skipping {}: resource type is not supported by CloudFormation output",
+            resource.address()
+        );
+    }
+
+    s3::to_resources(&supported)
+}
+
+/// Converts an attribute value into a CloudFormation property value. References to
+/// resources in the same template become `Ref`/`Fn::GetAtt` intrinsic functions.
+pub fn value_to_json(value: &ir::Value) -> JsonValue {
+    match value {
+        ir::Value::Bool(value) => json!(value),
+        ir::Value::Number(value) => json!(value),
+        ir::Value::String(value) => json!(value),
+        ir::Value::Json(document) => document.clone(),
+        ir::Value::List(values) => values.iter().map(value_to_json).collect(),
+        ir::Value::Map(entries) => entries
+            .iter()
+            .map(|(key, value)| (key.clone(), json!(value)))
+            .collect::<serde_json::Map<_, _>>()
+            .into(),
+        ir::Value::Block(attributes) => attributes_to_json(attributes),
+        ir::Value::Blocks(blocks) => blocks.iter().map(attributes_to_json).collect(),
+        ir::Value::Lookup(lookup) => json!(lookup.value),
+        ir::Value::Variable(variable) => variable
+            .fallback()
+            .map(value_to_json)
+            .unwrap_or(JsonValue::Null),
+        ir::Value::Reference(reference) => {
+            match s3::logical_id(&reference.resource_type, &reference.name) {
+                Some(logical_id) if reference.attribute == "arn" => {
+                    json!({ "Fn::GetAtt": [logical_id, "Arn"] })
+                }
+                Some(logical_id) => json!({ "Ref": logical_id }),
+                None => json!(reference.value),
+            }
+        }
+    }
+}
+
+fn attributes_to_json(attributes: &ir::Attributes) -> JsonValue {
+    attributes
+        .iter()
+        .map(|(key, value)| (key.to_string(), value_to_json(value)))
+        .collect::<serde_json::Map<_, _>>()
+        .into()
+}
@@ -0,0 +1,229 @@
+use serde_json::{Map, Value as JsonValue, json};
+
+use crate::{
+    cloudformation::{Resource, normalize_logical_id, value_to_json},
+    core::ir,
+};
+
+/// The logical ID of the CloudFormation resource an S3 resource maps onto, if any.
+pub fn logical_id(resource_type: &str, name: &str) -> Option<String> {
+    let suffix = match resource_type {
+        "aws_s3_bucket" => "",
+        "aws_s3_bucket_policy" => "Policy",
+        _ => return None,
+    };
+
+    Some(normalize_logical_id(name) + suffix)
+}
+
+/// Whether the resource type is rendered by [`to_resources`].
+pub fn supports(resource_type: &str) -> bool {
+    matches!(
+        resource_type,
+        "aws_s3_bucket"
+            | "aws_s3_bucket_policy"
+            | "aws_s3_bucket_public_access_block"
+            | "aws_s3_bucket_server_side_encryption_configuration"
+            | "aws_s3_bucket_versioning"
+            | "aws_s3_bucket_logging"
+            | "aws_s3_bucket_object_lock_configuration"
+            | "aws_s3_bucket_ownership_controls"
+            | "aws_s3_bucket_accelerate_configuration"
+    )
+}
+
+/// Converts S3 resources into `AWS::S3::Bucket` and `AWS::S3::BucketPolicy` resources.
+///
+/// Unlike Terraform, CloudFormation models bucket configuration as properties of the
+/// bucket itself rather than as separate resources, so sub-resources are folded into
+/// the bucket they belong to.
+pub fn to_resources(resources: &[ir::Resource]) -> Vec<Resource> {
+    let mut buckets: Vec<(&str, Resource)> = Vec::new();
+    let mut policies = Vec::new();
+
+    for resource in resources {
+        let attributes = &resource.attributes;
+
+        if resource.resource_type == "aws_s3_bucket" {
+            let mut properties = json!({ "BucketName": resource.import_id });
+
+            if let Some(object_lock_enabled) = attributes.get("object_lock_enabled") {
+                properties["ObjectLockEnabled"] = value_to_json(object_lock_enabled);
+            }
+
+            if let Some(ir::Value::Map(tags)) = attributes.get("tags") {
+                properties["Tags"] = tags
+                    .iter()
+                    .map(|(key, value)| json!({ "Key": key, "Value": value }))
+                    .collect();
+            }
+
+            buckets.push((
+                &resource.name,
+                Resource::new(
+                    normalize_logical_id(&resource.name),
+                    "AWS::S3::Bucket",
+                    properties,
+                    json!({ "BucketName": resource.import_id }),
+                ),
+            ));
+            continue;
+        }
+
+        if resource.resource_type == "aws_s3_bucket_policy" {
+            policies.push(Resource::new(
+                normalize_logical_id(&resource.name) + "Policy",
+                "AWS::S3::BucketPolicy",
+                json!({
+                    "Bucket": property(attributes, "bucket"),
+                    "PolicyDocument": property(attributes, "policy"),
+                }),
+                json!({ "Bucket": resource.import_id }),
+            ));
+            continue;
+        }
+
+        let Some((_, bucket)) = buckets.iter_mut().find(|(name, _)| *name == resource.name) else {
+            continue;
+        };
+
+        let JsonValue::Object(properties) = &mut bucket.properties else {
+            continue;
+        };
+
+        match resource.resource_type.as_str() {
+            "aws_s3_bucket_public_access_block" => {
+                properties.insert(
+                    "PublicAccessBlockConfiguration".to_string(),
+                    json!({
+                        "BlockPublicAcls": property(attributes, "block_public_acls"),
+                        "BlockPublicPolicy": property(attributes, "block_public_policy"),
+                        "IgnorePublicAcls": property(attributes, "ignore_public_acls"),
+                        "RestrictPublicBuckets": property(attributes, "restrict_public_buckets"),
+                    }),
+                );
+            }
+            "aws_s3_bucket_server_side_encryption_configuration" => {
+                let rules: Vec<JsonValue> = blocks(attributes, "rule")
+                    .map(|rule| {
+                        let mut sse_by_default = Map::new();
+
+                        if let Some(ir::Value::Block(default_encryption)) =
+                            rule.get("apply_server_side_encryption_by_default")
+                        {
+                            sse_by_default.insert(
+                                "SSEAlgorithm".to_string(),
+                                property(default_encryption, "sse_algorithm"),
+                            );
+
+                            if default_encryption.get("kms_master_key_id").is_some() {
+                                sse_by_default.insert(
+                                    "KMSMasterKeyID".to_string(),
+                                    property(default_encryption, "kms_master_key_id"),
+                                );
+                            }
+                        }
+
+                        json!({
+                            "ServerSideEncryptionByDefault": sse_by_default,
+                            "BucketKeyEnabled": rule
+                                .get("bucket_key_enabled")
+                                .map(value_to_json)
+                                .unwrap_or(json!(false)),
+                        })
+                    })
+                    .collect();
+
+                properties.insert(
+                    "BucketEncryption".to_string(),
+                    json!({ "ServerSideEncryptionConfiguration": rules }),
+                );
+            }
+            "aws_s3_bucket_versioning" => {
+                if let Some(ir::Value::Block(versioning)) =
+                    attributes.get("versioning_configuration")
+                {
+                    properties.insert(
+                        "VersioningConfiguration".to_string(),
+                        json!({ "Status": property(versioning, "status") }),
+                    );
+                }
+            }
+            "aws_s3_bucket_logging" => {
+                properties.insert(
+                    "LoggingConfiguration".to_string(),
+                    json!({
+                        "DestinationBucketName": property(attributes, "target_bucket"),
+                        "LogFilePrefix": property(attributes, "target_prefix"),
+                    }),
+                );
+            }
+            "aws_s3_bucket_object_lock_configuration" => {
+                if let Some(ir::Value::Block(rule)) = attributes.get("rule")
+                    && let Some(ir::Value::Block(retention)) = rule.get("default_retention")
+                {
+                    let mut default_retention = Map::new();
+
+                    for (key, property_name) in
+                        [("mode", "Mode"), ("days", "Days"), ("years", "Years")]
+                    {
+                        if let Some(value) = retention.get(key) {
+                            default_retention
+                                .insert(property_name.to_string(), value_to_json(value));
+                        }
+                    }
+
+                    properties.insert(
+                        "ObjectLockConfiguration".to_string(),
+                        json!({
+                            "ObjectLockEnabled": "Enabled",
+                            "Rule": { "DefaultRetention": default_retention },
+                        }),
+                    );
+                }
+            }
+            "aws_s3_bucket_ownership_controls" => {
+                if let Some(ir::Value::Block(rule)) = attributes.get("rule") {
+                    properties.insert(
+                        "OwnershipControls".to_string(),
+                        json!({
+                            "Rules": [
+                                { "ObjectOwnership": property(rule, "object_ownership") }
+                            ],
+                        }),
+                    );
+                }
+            }
+            "aws_s3_bucket_accelerate_configuration" => {
+                properties.insert(
+                    "AccelerateConfiguration".to_string(),
+                    json!({ "AccelerationStatus": property(attributes, "status") }),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    buckets
+        .into_iter()
+        .map(|(_, bucket)| bucket)
+        .chain(policies)
+        .collect()
+}
+
+fn property(attributes: &ir::Attributes, key: &str) -> JsonValue {
+    attributes
+        .get(key)
+        .map(value_to_json)
+        .unwrap_or(JsonValue::Null)
+}
+
+fn blocks<'a>(
+    attributes: &'a ir::Attributes,
+    key: &str,
+) -> impl Iterator<Item = &'a ir::Attributes> {
+    match attributes.get(key) {
+        Some(ir::Value::Blocks(blocks)) => blocks.iter(),
+        _ => [].iter(),
+    }
+}
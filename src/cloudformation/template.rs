@@ -0,0 +1,115 @@
+use anyhow::Result;
+use serde_json::{Map, Value, json};
+
+use crate::{
+    cloudformation,
+    core::{Backend, ir},
+};
+
+const TEMPLATE_FORMAT_VERSION: &str = "2010-09-09";
+
+/// A resource within a generated CloudFormation template.
+pub struct Resource {
+    pub logical_id: String,
+    pub resource_type: String,
+    pub properties: Value,
+    /// The primary identifier used to import the existing resource into a stack.
+    pub identifier: Value,
+}
+
+impl Resource {
+    pub fn new(
+        logical_id: impl Into<String>,
+        resource_type: impl Into<String>,
+        properties: Value,
+        identifier: Value,
+    ) -> Self {
+        Self {
+            logical_id: logical_id.into(),
+            resource_type: resource_type.into(),
+            properties,
+            identifier,
+        }
+    }
+}
+
+/// The syntax a CloudFormation template is rendered in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TemplateFormat {
+    Yaml,
+    Json,
+}
+
+/// Renders resources as a CloudFormation template.
+pub struct TemplateBackend {
+    format: TemplateFormat,
+}
+
+impl TemplateBackend {
+    pub fn new(format: TemplateFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl Backend for TemplateBackend {
+    fn render(&self, resources: &[ir::Resource]) -> Result<String> {
+        let template = to_template(&cloudformation::to_resources(resources));
+
+        match self.format {
+            TemplateFormat::Yaml => Ok(serde_yaml::to_string(&template)?),
+            TemplateFormat::Json => Ok(serde_json::to_string_pretty(&template)? + "\n"),
+        }
+    }
+}
+
+/// Renders the resources-to-import document for the resources of a CloudFormation
+/// template.
+pub struct ResourcesToImportBackend;
+
+impl Backend for ResourcesToImportBackend {
+    fn render(&self, resources: &[ir::Resource]) -> Result<String> {
+        let document = to_resources_to_import(&cloudformation::to_resources(resources));
+        Ok(serde_json::to_string_pretty(&document)? + "\n")
+    }
+}
+
+/// Builds a template document holding the given resources.
+fn to_template(resources: &[Resource]) -> Value {
+    let resources: Map<String, Value> = resources
+        .iter()
+        .map(|resource| {
+            // Imported resources are required to declare a deletion policy. Retaining
+            // them also protects existing infrastructure if the stack is deleted.
+            (
+                resource.logical_id.clone(),
+                json!({
+                    "Type": resource.resource_type,
+                    "DeletionPolicy": "Retain",
+                    "UpdateReplacePolicy": "Retain",
+                    "Properties": resource.properties,
+                }),
+            )
+        })
+        .collect();
+
+    json!({
+        "AWSTemplateFormatVersion": TEMPLATE_FORMAT_VERSION,
+        "Description": "Generated by iacgen",
+        "Resources": resources,
+    })
+}
+
+/// Builds the resources-to-import document accepted by `IMPORT` change sets, e.g.
+/// `aws cloudformation create-change-set --resources-to-import file://imports.json`.
+fn to_resources_to_import(resources: &[Resource]) -> Value {
+    resources
+        .iter()
+        .map(|resource| {
+            json!({
+                "ResourceType": resource.resource_type,
+                "LogicalResourceId": resource.logical_id,
+                "ResourceIdentifier": resource.identifier,
+            })
+        })
+        .collect()
+}
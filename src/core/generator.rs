@@ -1,12 +1,20 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
+use tracing::warn;
 
-use crate::core::traits::{IntoTerraform, OutputWriter, ResourceFetcher, TerraformGenerator};
+use crate::core::{
+    Outputs,
+    ir::Resource,
+    references,
+    traits::{IntoResources, ResourceFetcher},
+};
 
 pub struct Generator<F>
 where
     F: ResourceFetcher,
 {
     fetcher: F,
+    region: Option<String>,
+    strict: bool,
 }
 
 impl<F> Generator<F>
@@ -14,19 +22,62 @@ where
     F: ResourceFetcher,
 {
     pub fn new(fetcher: F) -> Self {
-        Self { fetcher }
+        Self {
+            fetcher,
+            region: None,
+            strict: false,
+        }
+    }
+
+    /// Sets the region the fetcher was configured with, whose resources are managed by
+    /// the default provider.
+    pub fn with_region(mut self, region: Option<String>) -> Self {
+        self.region = region;
+        self
+    }
+
+    /// Fails generation if any setting could not be fetched, rather than writing output
+    /// that silently lacks it.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
     }
 
-    pub async fn generate(&self, writer: &mut Box<dyn OutputWriter>) -> Result<()> {
+    pub async fn generate(&self, outputs: &mut Outputs) -> Result<()> {
         let aws_resources = self.fetcher.fetch().await?;
 
-        for aws_resource in aws_resources {
-            let tf_resource = aws_resource.into_terraform();
-            let hcl = tf_resource.to_hcl();
-            writer.write(&hcl)?;
-            writer.write("\n")?;
+        let mut warnings = self.fetcher.report().warnings();
+
+        if !warnings.is_empty() {
+            warnings.sort_by(|a, b| a.resource.cmp(&b.resource));
+
+            warn!(
+                "{} setting(s) could not be fetched and are missing from the output:",
+                warnings.len()
+            );
+            for warning in &warnings {
+                warn!("  {}", warning);
+            }
+
+            if self.strict {
+                bail!("Settings could not be fetched, not writing incomplete output");
+            }
         }
 
-        Ok(())
+        let mut resources: Vec<Resource> = aws_resources
+            .into_iter()
+            .flat_map(IntoResources::into_resources)
+            .map(|mut resource| {
+                resource.service = F::SERVICE.to_string();
+                if resource.region == self.region {
+                    resource.region = None;
+                }
+                resource
+            })
+            .collect();
+
+        references::resolve(&mut resources);
+
+        outputs.write(&resources)
     }
 }
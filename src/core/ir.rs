@@ -0,0 +1,369 @@
+//! A backend-neutral representation of fetched resources.
+//!
+//! Fetchers convert what they discover into [`Resource`]s, and output backends render
+//! those without knowing which service they came from. Resource types and attribute
+//! names follow the Terraform AWS provider schema, since it covers every resource
+//! iacgen supports and the other backends map from it.
+
+use std::fmt;
+
+/// A single resource, e.g. an `aws_s3_bucket_versioning`.
+#[derive(Clone, Debug)]
+pub struct Resource {
+    pub resource_type: String,
+    /// The normalized logical name, unique among resources of the same type.
+    pub name: String,
+    /// The identifier used to adopt the existing resource into IaC management.
+    pub import_id: String,
+    /// Values other resources may use to refer to this one, keyed by the attribute
+    /// holding them, e.g. a bucket's `id` and `arn`.
+    pub identifiers: Vec<(String, String)>,
+    /// The service the resource was fetched from, e.g. `s3`.
+    pub service: String,
+    /// The name of the top-level resource this one belongs to, e.g. the bucket a
+    /// bucket policy is attached to. Top-level resources are their own group.
+    pub group: String,
+    /// The region the resource lives in, if it needs a provider configured for that
+    /// region. Fetchers record the region of everything they find, and the generator
+    /// clears it for resources in the region it was configured with.
+    pub region: Option<String>,
+    pub attributes: Attributes,
+    /// Resources that must be created before this one although none of its attributes
+    /// refer to them, as `(resource_type, name)` pairs.
+    pub depends_on: Vec<(String, String)>,
+    /// Attributes whose real values cannot be fetched, e.g. a function's deployment
+    /// package, so that differences from the configuration are ignored.
+    pub ignore_changes: Vec<String>,
+}
+
+impl Resource {
+    pub fn new(
+        resource_type: impl Into<String>,
+        name: impl Into<String>,
+        import_id: impl Into<String>,
+    ) -> Self {
+        let name = name.into();
+
+        Self {
+            resource_type: resource_type.into(),
+            group: name.clone(),
+            name,
+            import_id: import_id.into(),
+            identifiers: Vec::new(),
+            service: String::new(),
+            region: None,
+            attributes: Attributes::new(),
+            depends_on: Vec::new(),
+            ignore_changes: Vec::new(),
+        }
+    }
+
+    pub fn with_group(mut self, group: impl Into<String>) -> Self {
+        self.group = group.into();
+        self
+    }
+
+    pub fn with_region(mut self, region: impl Into<String>) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn with_identifier(
+        mut self,
+        attribute: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.identifiers.push((attribute.into(), value.into()));
+        self
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.attributes.insert(key, value);
+        self
+    }
+
+    pub fn with_optional<V>(mut self, key: impl Into<String>, value: Option<V>) -> Self
+    where
+        V: Into<Value>,
+    {
+        self.attributes = self.attributes.with_optional(key, value);
+        self
+    }
+
+    pub fn with_dependency(mut self, resource: &Resource) -> Self {
+        self.depends_on
+            .push((resource.resource_type.clone(), resource.name.clone()));
+        self
+    }
+
+    pub fn with_ignore_changes(mut self, attributes: &[&str]) -> Self {
+        self.ignore_changes
+            .extend(attributes.iter().map(|attribute| attribute.to_string()));
+        self
+    }
+
+    /// Input variables any of this resource's attributes are supplied through.
+    pub fn variables(&self) -> Vec<&Variable> {
+        let mut variables = Vec::new();
+        collect_variables(&self.attributes, &mut variables);
+        variables
+    }
+
+    /// A reference to one of this resource's attributes, whose literal value is known
+    /// to be `value`.
+    pub fn reference(&self, attribute: impl Into<String>, value: impl Into<String>) -> Reference {
+        Reference {
+            resource_type: self.resource_type.clone(),
+            name: self.name.clone(),
+            attribute: attribute.into(),
+            value: value.into(),
+        }
+    }
+
+    /// The Terraform-style address of this resource, e.g. `aws_s3_bucket.logs`.
+    pub fn address(&self) -> String {
+        format!("{}.{}", self.resource_type, self.name)
+    }
+}
+
+/// An ordered set of attributes or nested block contents.
+#[derive(Clone, Debug, Default)]
+pub struct Attributes(Vec<(String, Value)>);
+
+impl Attributes {
+    pub fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    pub fn with(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    pub fn with_optional<V>(mut self, key: impl Into<String>, value: Option<V>) -> Self
+    where
+        V: Into<Value>,
+    {
+        if let Some(value) = value {
+            self.insert(key, value);
+        }
+        self
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        let key = key.into();
+        let value = value.into();
+
+        match self.0.iter_mut().find(|(existing, _)| *existing == key) {
+            Some((_, existing)) => *existing = value,
+            None => self.0.push((key, value)),
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0
+            .iter()
+            .find(|(existing, _)| existing == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut Value)> {
+        self.0.iter_mut().map(|(key, value)| (key.as_str(), value))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Value {
+    Bool(bool),
+    Number(i64),
+    String(String),
+    /// An embedded JSON document, such as an IAM policy.
+    Json(serde_json::Value),
+    List(Vec<Value>),
+    /// A map of arbitrary string keys, such as tags.
+    Map(Vec<(String, String)>),
+    /// A nested block that may appear at most once.
+    Block(Attributes),
+    /// A nested block that may be repeated.
+    Blocks(Vec<Attributes>),
+    Reference(Reference),
+    /// A value that identifies another resource, e.g. a bucket name. It becomes a
+    /// reference if that resource is generated in the same run.
+    Lookup(Lookup),
+    /// A value supplied through an input variable rather than written into the
+    /// configuration, e.g. one that may be secret.
+    Variable(Variable),
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Number(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Number(value.into())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value::String(value.clone())
+    }
+}
+
+impl From<serde_json::Value> for Value {
+    fn from(value: serde_json::Value) -> Self {
+        Value::Json(value)
+    }
+}
+
+impl From<Reference> for Value {
+    fn from(value: Reference) -> Self {
+        Value::Reference(value)
+    }
+}
+
+impl From<Lookup> for Value {
+    fn from(value: Lookup) -> Self {
+        Value::Lookup(value)
+    }
+}
+
+impl From<Variable> for Value {
+    fn from(value: Variable) -> Self {
+        Value::Variable(value)
+    }
+}
+
+impl<V> From<Vec<V>> for Value
+where
+    V: Into<Value>,
+{
+    fn from(value: Vec<V>) -> Self {
+        Value::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+/// A reference to an attribute of another generated resource.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reference {
+    pub resource_type: String,
+    pub name: String,
+    pub attribute: String,
+    /// The literal value the reference resolves to, for backends that cannot express
+    /// references.
+    pub value: String,
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.resource_type, self.name, self.attribute)
+    }
+}
+
+/// A literal value identifying a resource of one of `resource_types`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lookup {
+    pub resource_types: Vec<String>,
+    pub value: String,
+}
+
+impl Lookup {
+    pub fn new(resource_types: &[&str], value: impl Into<String>) -> Self {
+        Self {
+            resource_types: resource_types.iter().map(|t| t.to_string()).collect(),
+            value: value.into(),
+        }
+    }
+}
+
+/// An input variable of the generated configuration. Backends that cannot express
+/// variables fall back to its current value.
+#[derive(Clone, Debug)]
+pub struct Variable {
+    pub name: String,
+    pub description: String,
+    /// The value used unless another is supplied. Variables without one must be set
+    /// when planning.
+    pub default: Option<Box<Value>>,
+    /// The value the fetched resource currently has, if it is known.
+    pub value: Option<Box<Value>>,
+    /// Whether the value is hidden from plan output.
+    pub sensitive: bool,
+}
+
+impl Variable {
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            default: None,
+            value: None,
+            sensitive: false,
+        }
+    }
+
+    pub fn with_default(mut self, default: impl Into<Value>) -> Self {
+        self.default = Some(Box::new(default.into()));
+        self
+    }
+
+    pub fn with_value(mut self, value: impl Into<Value>) -> Self {
+        self.value = Some(Box::new(value.into()));
+        self
+    }
+
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
+
+    /// The value backends that cannot express variables write instead.
+    pub fn fallback(&self) -> Option<&Value> {
+        self.value.as_deref().or(self.default.as_deref())
+    }
+}
+
+fn collect_variables<'a>(attributes: &'a Attributes, variables: &mut Vec<&'a Variable>) {
+    for (_, value) in attributes.iter() {
+        collect_value_variables(value, variables);
+    }
+}
+
+fn collect_value_variables<'a>(value: &'a Value, variables: &mut Vec<&'a Variable>) {
+    match value {
+        Value::Variable(variable) => variables.push(variable),
+        Value::List(values) => values
+            .iter()
+            .for_each(|value| collect_value_variables(value, variables)),
+        Value::Block(attributes) => collect_variables(attributes, variables),
+        Value::Blocks(blocks) => blocks
+            .iter()
+            .for_each(|attributes| collect_variables(attributes, variables)),
+        _ => {}
+    }
+}
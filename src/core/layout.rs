@@ -0,0 +1,39 @@
+use clap::ValueEnum;
+
+use crate::core::ir::Resource;
+
+/// How resources are split across files when writing to a directory.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Layout {
+    /// One file per top-level resource and its sub-resources, e.g. per bucket
+    #[default]
+    #[value(alias = "bucket")]
+    Resource,
+    /// One file per resource type
+    Type,
+    /// One file per service
+    Service,
+}
+
+impl Layout {
+    /// Splits resources into named groups, each destined for its own file, keeping the
+    /// order in which groups and resources were first seen.
+    pub fn partition(&self, resources: &[Resource]) -> Vec<(String, Vec<Resource>)> {
+        let mut groups: Vec<(String, Vec<Resource>)> = Vec::new();
+
+        for resource in resources {
+            let key = match self {
+                Layout::Resource => &resource.group,
+                Layout::Type => &resource.resource_type,
+                Layout::Service => &resource.service,
+            };
+
+            match groups.iter_mut().find(|(existing, _)| existing == key) {
+                Some((_, group)) => group.push(resource.clone()),
+                None => groups.push((key.clone(), vec![resource.clone()])),
+            }
+        }
+
+        groups
+    }
+}
@@ -1,5 +1,13 @@
 mod generator;
+pub mod ir;
+mod layout;
+mod outputs;
+pub mod references;
+mod report;
 mod traits;
 
 pub use generator::Generator;
-pub use traits::{IntoTerraform, OutputWriter, ResourceFetcher, TerraformGenerator};
+pub use layout::Layout;
+pub use outputs::Outputs;
+pub use report::{FetchError, Report};
+pub use traits::{Backend, IntoResources, OutputWriter, ResourceFetcher};
@@ -0,0 +1,71 @@
+use anyhow::Result;
+
+use crate::core::{Backend, Layout, OutputWriter, ir::Resource};
+
+/// The set of destinations a generation run writes to, each paired with the backend
+/// that renders its content.
+pub struct Outputs {
+    outputs: Vec<Output>,
+}
+
+struct Output {
+    backend: Box<dyn Backend>,
+    writer: Box<dyn OutputWriter>,
+    /// Set when the destination holds multiple files.
+    layout: Option<Layout>,
+}
+
+impl Outputs {
+    pub fn new() -> Self {
+        Self {
+            outputs: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, backend: Box<dyn Backend>, writer: Box<dyn OutputWriter>) -> Self {
+        self.outputs.push(Output {
+            backend,
+            writer,
+            layout: None,
+        });
+        self
+    }
+
+    pub fn with_files(
+        mut self,
+        backend: Box<dyn Backend>,
+        writer: Box<dyn OutputWriter>,
+        layout: Layout,
+    ) -> Self {
+        self.outputs.push(Output {
+            backend,
+            writer,
+            layout: Some(layout),
+        });
+        self
+    }
+
+    pub fn write(&mut self, resources: &[Resource]) -> Result<()> {
+        for output in &mut self.outputs {
+            match output.layout {
+                Some(layout) => {
+                    for (name, content) in output.backend.render_files(resources, layout)? {
+                        output.writer.begin_file(&name)?;
+                        output.writer.write(&content)?;
+                    }
+                }
+                None => output.writer.write(&output.backend.render(resources)?)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        for output in &mut self.outputs {
+            output.writer.flush()?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::core::ir::{Attributes, Reference, Resource, Value};
+
+/// Resolves every [`Value::Lookup`] across a run's resources. Lookups of resources that
+/// are part of the run become references, which gives the generated configuration a
+/// correct dependency graph; all others fall back to their literal value.
+pub fn resolve(resources: &mut [Resource]) {
+    let mut index = HashMap::new();
+
+    for resource in resources.iter() {
+        for (attribute, value) in &resource.identifiers {
+            index
+                .entry((resource.resource_type.clone(), value.clone()))
+                .or_insert_with(|| resource.reference(attribute, value));
+        }
+    }
+
+    for resource in resources.iter_mut() {
+        resolve_attributes(&mut resource.attributes, &index);
+    }
+}
+
+fn resolve_attributes(attributes: &mut Attributes, index: &HashMap<(String, String), Reference>) {
+    for (_, value) in attributes.iter_mut() {
+        resolve_value(value, index);
+    }
+}
+
+fn resolve_value(value: &mut Value, index: &HashMap<(String, String), Reference>) {
+    match value {
+        Value::Lookup(lookup) => {
+            let reference = lookup.resource_types.iter().find_map(|resource_type| {
+                index
+                    .get(&(resource_type.clone(), lookup.value.clone()))
+                    .cloned()
+            });
+
+            *value = match reference {
+                Some(reference) => Value::Reference(reference),
+                None => Value::String(lookup.value.clone()),
+            };
+        }
+        Value::List(values) => values
+            .iter_mut()
+            .for_each(|value| resolve_value(value, index)),
+        Value::Block(attributes) => resolve_attributes(attributes, index),
+        Value::Blocks(blocks) => blocks
+            .iter_mut()
+            .for_each(|attributes| resolve_attributes(attributes, index)),
+        _ => {}
+    }
+}
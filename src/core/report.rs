@@ -0,0 +1,76 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+/// An unexpected error from a provider API, such as access being denied or throttling,
+/// as opposed to the requested setting simply not being configured.
+#[derive(Clone, Debug)]
+pub struct FetchError {
+    /// The error code returned by the API, if the request got that far.
+    pub code: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{}: {}", code, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// A setting that could not be fetched, and is therefore missing from the output.
+#[derive(Clone, Debug)]
+pub struct FetchWarning {
+    /// The resource the setting belongs to, e.g. a bucket name.
+    pub resource: String,
+    /// The setting that could not be fetched, e.g. `policy`.
+    pub setting: String,
+    pub error: FetchError,
+}
+
+impl fmt::Display for FetchWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.resource, self.setting, self.error)
+    }
+}
+
+/// Collects warnings over a run. Clones share the same collection, so concurrent fetch
+/// tasks can all report into it.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    warnings: Arc<Mutex<Vec<FetchWarning>>>,
+}
+
+impl Report {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the fetched value, or records the error as a warning about `resource`'s
+    /// `setting` and returns `None`.
+    pub fn check<T>(
+        &self,
+        resource: &str,
+        setting: &str,
+        result: Result<T, FetchError>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.warnings.lock().unwrap().push(FetchWarning {
+                    resource: resource.to_string(),
+                    setting: setting.to_string(),
+                    error,
+                });
+                None
+            }
+        }
+    }
+
+    pub fn warnings(&self) -> Vec<FetchWarning> {
+        self.warnings.lock().unwrap().clone()
+    }
+}
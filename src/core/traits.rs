@@ -1,22 +1,45 @@
-use anyhow::Result;
+use anyhow::{Result, bail};
 use async_trait::async_trait;
 
+use crate::core::{Layout, Report, ir};
+
 #[async_trait]
 pub trait ResourceFetcher {
-    type Resource: IntoTerraform;
+    /// The service resources are fetched from, e.g. `s3`.
+    const SERVICE: &'static str;
+
+    type Resource: IntoResources;
     async fn fetch(&self) -> Result<Vec<Self::Resource>>;
+
+    /// Warnings about settings that could not be fetched during `fetch`.
+    fn report(&self) -> Report {
+        Report::new()
+    }
 }
 
-pub trait IntoTerraform {
-    type TerraformResource: TerraformGenerator;
-    fn into_terraform(self) -> Self::TerraformResource;
+pub trait IntoResources {
+    fn into_resources(self) -> Vec<ir::Resource>;
 }
 
-pub trait TerraformGenerator {
-    fn to_hcl(&self) -> String;
+pub trait Backend {
+    fn render(&self, resources: &[ir::Resource]) -> Result<String>;
+
+    /// Renders resources as a set of named files, split according to `layout`.
+    fn render_files(
+        &self,
+        _resources: &[ir::Resource],
+        _layout: Layout,
+    ) -> Result<Vec<(String, String)>> {
+        bail!("This format does not support writing to a directory")
+    }
 }
 
 pub trait OutputWriter {
+    /// Starts a new named file. Writers that produce a single document ignore file
+    /// boundaries.
+    fn begin_file(&mut self, _name: &str) -> Result<()> {
+        Ok(())
+    }
     fn write(&mut self, content: &str) -> Result<()>;
     fn flush(&mut self) -> Result<()> {
         Ok(())
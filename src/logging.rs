@@ -11,7 +11,7 @@ pub fn init(args: &crate::cli::Args) {
 
     let time_format =
         time::format_description::parse("[hour]:[minute]:[second].[subsecond digits:3]").unwrap();
-    let time_offset = UtcOffset::current_local_offset().unwrap_or_else(|_| UtcOffset::UTC);
+    let time_offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
     let timer = OffsetTime::new(time_offset, time_format);
 
     tracing_subscriber::fmt()
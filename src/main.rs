@@ -1,18 +1,31 @@
 mod aws;
 mod cli;
+mod cloudformation;
 mod core;
 mod logging;
 mod output;
+mod pulumi;
 mod terraform;
 
-use aws_config::BehaviorVersion;
+use anyhow::{Result, bail};
+use aws_config::{BehaviorVersion, SdkConfig};
 use tracing::{error, info};
 
 use crate::{
-    aws::s3::S3Fetcher,
-    cli::Command,
-    core::{Generator, OutputWriter},
-    output::{FileWriter, StdoutWriter},
+    aws::{
+        ec2::{SecurityGroupFetcher, VpcFetcher},
+        iam::{IamFetcher, IamIdentityFetcher},
+        lambda::LambdaFetcher,
+        s3::S3Fetcher,
+    },
+    cli::{Args, Command, Format, StateBackendKind},
+    cloudformation::{ResourcesToImportBackend, TemplateBackend, TemplateFormat},
+    core::{Backend, Generator, Outputs, ResourceFetcher},
+    output::{DirectoryWriter, FileWriter, ScriptWriter, StdoutWriter},
+    pulumi::ProgramBackend,
+    terraform::{
+        ConfigBackend, ImportScriptBackend, RootModule, StateBackend, Syntax, TerraformBackend,
+    },
 };
 
 #[tokio::main]
@@ -23,31 +36,182 @@ async fn main() {
 
     let mut config_loader = aws_config::defaults(BehaviorVersion::latest());
 
-    if let Some(profile) = args.profile {
+    if let Some(profile) = &args.profile {
         config_loader = config_loader.profile_name(profile);
     }
 
     let config = config_loader.load().await;
 
-    let mut writer: Box<dyn OutputWriter> = if let Some(output_path) = args.output {
-        info!(
-            "Writing Terraform configuration to {}",
-            output_path.display()
+    let mut outputs = match outputs(&args, &config) {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            error!("{}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let region = config.region().map(|region| region.to_string());
+
+    let result = match args.command {
+        Command::S3 => generate(S3Fetcher::new(config), region, &args, &mut outputs).await,
+        Command::Iam => generate(IamFetcher::new(config), region, &args, &mut outputs).await,
+        Command::IamIdentities => {
+            generate(IamIdentityFetcher::new(config), region, &args, &mut outputs).await
+        }
+        Command::Lambda {
+            environment_as_variables,
+        } => {
+            generate(
+                LambdaFetcher::new(config).with_environment_as_variables(environment_as_variables),
+                region,
+                &args,
+                &mut outputs,
+            )
+            .await
+        }
+        Command::SecurityGroups => {
+            generate(
+                SecurityGroupFetcher::new(config),
+                region,
+                &args,
+                &mut outputs,
+            )
+            .await
+        }
+        Command::Vpc => generate(VpcFetcher::new(config), region, &args, &mut outputs).await,
+    };
+
+    if let Err(e) = result {
+        error!("Failed to generate configuration: {}", e);
+        std::process::exit(1);
+    }
+
+    outputs.flush().unwrap();
+}
+
+async fn generate<F>(
+    fetcher: F,
+    region: Option<String>,
+    args: &Args,
+    outputs: &mut Outputs,
+) -> Result<()>
+where
+    F: ResourceFetcher,
+{
+    Generator::new(fetcher)
+        .with_region(region)
+        .with_strict(args.strict)
+        .generate(outputs)
+        .await
+}
+
+fn outputs(args: &Args, config: &SdkConfig) -> Result<Outputs> {
+    if args.backend.is_some() && !args.format.is_terraform() {
+        bail!("Backends are only generated for Terraform formats");
+    }
+
+    let backend: Box<dyn Backend> = match args.format {
+        Format::Hcl => Box::new(
+            ConfigBackend::new(Syntax::Hcl)
+                .with_import_blocks(args.import_blocks)
+                .with_root_module(root_module(args, config)),
+        ),
+        Format::Json => Box::new(
+            ConfigBackend::new(Syntax::Json)
+                .with_import_blocks(args.import_blocks)
+                .with_root_module(root_module(args, config)),
+        ),
+        Format::CloudformationYaml => Box::new(TemplateBackend::new(TemplateFormat::Yaml)),
+        Format::CloudformationJson => Box::new(TemplateBackend::new(TemplateFormat::Json)),
+        Format::PulumiYaml => Box::new(ProgramBackend),
+    };
+
+    let mut outputs = Outputs::new();
+
+    if let Some(output_dir) = &args.output_dir {
+        if !args.format.is_terraform() {
+            bail!("Only Terraform formats can be written to a directory");
+        }
+
+        info!("Writing configuration to {}", output_dir.display());
+        outputs = outputs.with_files(
+            backend,
+            Box::new(DirectoryWriter::new(output_dir.clone())),
+            args.layout,
         );
-        Box::new(FileWriter::new(output_path))
+    } else if let Some(output_path) = &args.output {
+        info!("Writing configuration to {}", output_path.display());
+        outputs = outputs.with(backend, Box::new(FileWriter::new(output_path.clone())));
     } else {
-        info!("Writing Terraform configuration to stdout");
-        Box::new(StdoutWriter)
-    };
+        info!("Writing configuration to stdout");
+        outputs = outputs.with(backend, Box::new(StdoutWriter));
+    }
 
-    match args.command {
-        Command::S3 => {
-            let s3_fetcher = S3Fetcher::new(config);
-            let generator = Generator::new(s3_fetcher);
-            if let Err(e) = generator.generate(&mut writer).await {
-                error!("Failed to generate Terraform configuration: {}", e);
-            }
-            writer.flush().unwrap();
+    if let Some(import_script_path) = &args.import_script {
+        if !args.format.is_terraform() {
+            bail!("Import scripts are only generated for Terraform formats");
         }
+
+        info!(
+            "Writing Terraform import script to {}",
+            import_script_path.display()
+        );
+        outputs = outputs.with(
+            Box::new(ImportScriptBackend),
+            Box::new(ScriptWriter::new(import_script_path.clone())),
+        );
+    }
+
+    if let Some(state_path) = &args.state {
+        if !args.format.is_terraform() {
+            bail!("State files are only generated for Terraform formats");
+        }
+
+        info!("Writing Terraform state to {}", state_path.display());
+        outputs = outputs.with(
+            Box::new(StateBackend),
+            Box::new(FileWriter::new(state_path.clone())),
+        );
+    }
+
+    if let Some(resources_to_import_path) = &args.resources_to_import {
+        if !args.format.is_cloudformation() {
+            bail!("Resources to import are only generated for CloudFormation formats");
+        }
+
+        info!(
+            "Writing CloudFormation resources to import to {}",
+            resources_to_import_path.display()
+        );
+        outputs = outputs.with(
+            Box::new(ResourcesToImportBackend),
+            Box::new(FileWriter::new(resources_to_import_path.clone())),
+        );
+    }
+
+    Ok(outputs)
+}
+
+fn root_module(args: &Args, config: &SdkConfig) -> RootModule {
+    let mut root_module = RootModule::new(args.provider_version.clone())
+        .with_region(config.region().map(|region| region.to_string()))
+        .with_profile(args.profile.clone());
+
+    match args.backend {
+        Some(StateBackendKind::S3) => {
+            root_module = root_module.with_backend(TerraformBackend::S3 {
+                bucket: args.backend_bucket.clone().unwrap_or_default(),
+                key: args.backend_key.clone().unwrap_or_default(),
+                region: args.backend_region.clone(),
+            });
+        }
+        Some(StateBackendKind::Local) => {
+            root_module = root_module.with_backend(TerraformBackend::Local {
+                path: args.backend_path.clone(),
+            });
+        }
+        None => {}
     }
+
+    root_module
 }
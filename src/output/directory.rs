@@ -0,0 +1,44 @@
+use anyhow::{Result, bail};
+use std::path::PathBuf;
+
+use crate::core::OutputWriter;
+
+/// Writes each file of a multi-file output into a directory.
+pub struct DirectoryWriter {
+    path: PathBuf,
+    files: Vec<(String, String)>,
+}
+
+impl DirectoryWriter {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            files: Vec::new(),
+        }
+    }
+}
+
+impl OutputWriter for DirectoryWriter {
+    fn begin_file(&mut self, name: &str) -> Result<()> {
+        self.files.push((name.to_string(), String::new()));
+        Ok(())
+    }
+
+    fn write(&mut self, content: &str) -> Result<()> {
+        match self.files.last_mut() {
+            Some((_, buffer)) => buffer.push_str(content),
+            None => bail!("No file started in {}", self.path.display()),
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        std::fs::create_dir_all(&self.path)?;
+
+        for (name, content) in self.files.drain(..) {
+            std::fs::write(self.path.join(name), content)?;
+        }
+
+        Ok(())
+    }
+}
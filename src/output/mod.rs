@@ -1,5 +1,9 @@
+mod directory;
 mod file;
+mod script;
 mod stdout;
 
+pub use directory::DirectoryWriter;
 pub use file::FileWriter;
+pub use script::ScriptWriter;
 pub use stdout::StdoutWriter;
@@ -0,0 +1,41 @@
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::core::OutputWriter;
+
+const SCRIPT_HEADER: &str = "#!/bin/sh\nset -e\n\n";
+
+/// Writes an executable shell script, e.g. a list of `terraform import` commands.
+pub struct ScriptWriter {
+    path: PathBuf,
+    buffer: String,
+}
+
+impl ScriptWriter {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            buffer: String::new(),
+        }
+    }
+}
+
+impl OutputWriter for ScriptWriter {
+    fn write(&mut self, content: &str) -> Result<()> {
+        self.buffer.push_str(content);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        std::fs::write(&self.path, format!("{}{}", SCRIPT_HEADER, self.buffer))?;
+        self.buffer.clear();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o755))?;
+        }
+
+        Ok(())
+    }
+}
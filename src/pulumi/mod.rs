@@ -0,0 +1,269 @@
+use anyhow::Result;
+use serde_json::{Map, Value as JsonValue, json};
+use tracing::warn;
+
+use crate::core::{
+    Backend,
+    ir::{Attributes, Reference, Resource, Value},
+};
+
+const PROJECT_NAME: &str = "iacgen";
+
+/// Maps a Terraform AWS provider resource type onto the equivalent Pulumi AWS type token.
+fn type_token(resource_type: &str) -> Option<&'static str> {
+    let token = match resource_type {
+        "aws_s3_bucket" => "aws:s3:BucketV2",
+        "aws_s3_bucket_policy" => "aws:s3:BucketPolicy",
+        "aws_s3_bucket_public_access_block" => "aws:s3:BucketPublicAccessBlock",
+        "aws_s3_bucket_server_side_encryption_configuration" => {
+            "aws:s3:BucketServerSideEncryptionConfigurationV2"
+        }
+        "aws_s3_bucket_versioning" => "aws:s3:BucketVersioningV2",
+        "aws_s3_bucket_logging" => "aws:s3:BucketLoggingV2",
+        "aws_s3_bucket_lifecycle_configuration" => "aws:s3:BucketLifecycleConfigurationV2",
+        "aws_s3_bucket_replication_configuration" => "aws:s3:BucketReplicationConfig",
+        "aws_s3_bucket_cors_configuration" => "aws:s3:BucketCorsConfigurationV2",
+        "aws_s3_bucket_website_configuration" => "aws:s3:BucketWebsiteConfigurationV2",
+        "aws_s3_bucket_notification" => "aws:s3:BucketNotification",
+        "aws_s3_bucket_object_lock_configuration" => "aws:s3:BucketObjectLockConfigurationV2",
+        "aws_s3_bucket_ownership_controls" => "aws:s3:BucketOwnershipControls",
+        "aws_s3_bucket_acl" => "aws:s3:BucketAclV2",
+        "aws_s3_bucket_accelerate_configuration" => "aws:s3:BucketAccelerateConfigurationV2",
+        "aws_s3_bucket_request_payment_configuration" => {
+            "aws:s3:BucketRequestPaymentConfigurationV2"
+        }
+        "aws_s3_bucket_intelligent_tiering_configuration" => {
+            "aws:s3:BucketIntelligentTieringConfiguration"
+        }
+        "aws_s3_bucket_inventory" => "aws:s3:Inventory",
+        "aws_s3_bucket_analytics_configuration" => "aws:s3:AnalyticsConfiguration",
+        "aws_s3_bucket_metric" => "aws:s3:BucketMetric",
+        "aws_s3_access_point" => "aws:s3:AccessPoint",
+        "aws_s3control_access_point_policy" => "aws:s3control:AccessPointPolicy",
+        "aws_s3control_multi_region_access_point" => "aws:s3control:MultiRegionAccessPoint",
+        "aws_vpc" => "aws:ec2:Vpc",
+        "aws_default_vpc" => "aws:ec2:DefaultVpc",
+        "aws_vpc_dhcp_options" => "aws:ec2:VpcDhcpOptions",
+        "aws_vpc_dhcp_options_association" => "aws:ec2:VpcDhcpOptionsAssociation",
+        "aws_subnet" => "aws:ec2:Subnet",
+        "aws_default_subnet" => "aws:ec2:DefaultSubnet",
+        "aws_route_table" => "aws:ec2:RouteTable",
+        "aws_route" => "aws:ec2:Route",
+        "aws_route_table_association" => "aws:ec2:RouteTableAssociation",
+        "aws_internet_gateway" => "aws:ec2:InternetGateway",
+        "aws_nat_gateway" => "aws:ec2:NatGateway",
+        "aws_eip" => "aws:ec2:Eip",
+        "aws_vpc_endpoint" => "aws:ec2:VpcEndpoint",
+        "aws_security_group" => "aws:ec2:SecurityGroup",
+        "aws_default_security_group" => "aws:ec2:DefaultSecurityGroup",
+        "aws_vpc_security_group_ingress_rule" => "aws:vpc:SecurityGroupIngressRule",
+        "aws_vpc_security_group_egress_rule" => "aws:vpc:SecurityGroupEgressRule",
+        "aws_ec2_managed_prefix_list" => "aws:ec2:ManagedPrefixList",
+        "aws_iam_role" => "aws:iam:Role",
+        "aws_iam_policy" => "aws:iam:Policy",
+        "aws_iam_role_policy" => "aws:iam:RolePolicy",
+        "aws_iam_role_policy_attachment" => "aws:iam:RolePolicyAttachment",
+        "aws_iam_instance_profile" => "aws:iam:InstanceProfile",
+        "aws_iam_user" => "aws:iam:User",
+        "aws_iam_user_group_membership" => "aws:iam:UserGroupMembership",
+        "aws_iam_user_policy" => "aws:iam:UserPolicy",
+        "aws_iam_user_policy_attachment" => "aws:iam:UserPolicyAttachment",
+        "aws_iam_group" => "aws:iam:Group",
+        "aws_iam_group_policy" => "aws:iam:GroupPolicy",
+        "aws_iam_group_policy_attachment" => "aws:iam:GroupPolicyAttachment",
+        "aws_iam_openid_connect_provider" => "aws:iam:OpenIdConnectProvider",
+        "aws_iam_saml_provider" => "aws:iam:SamlProvider",
+        "aws_lambda_function" => "aws:lambda:Function",
+        "aws_lambda_alias" => "aws:lambda:Alias",
+        "aws_lambda_permission" => "aws:lambda:Permission",
+        "aws_lambda_event_source_mapping" => "aws:lambda:EventSourceMapping",
+        _ => return None,
+    };
+
+    Some(token)
+}
+
+/// Renders resources as a Pulumi YAML program (`Pulumi.yaml`), each with the `import`
+/// resource option set so that `pulumi up` adopts it.
+pub struct ProgramBackend;
+
+impl Backend for ProgramBackend {
+    fn render(&self, resources: &[Resource]) -> Result<String> {
+        Ok(serde_yaml::to_string(&to_program(resources))?)
+    }
+}
+
+fn to_program(resources: &[Resource]) -> JsonValue {
+    let mut program_resources = Map::new();
+
+    for resource in resources {
+        let Some(token) = type_token(&resource.resource_type) else {
+            warn!(
+                "Skipping {}: resource type is not supported by Pulumi output",
+                resource.address()
+            );
+            continue;
+        };
+
+        let mut options = json!({ "import": resource.import_id });
+
+        if let Some(region) = &resource.region {
+            let provider = provider_name(region);
+
+            program_resources
+                .entry(provider.clone())
+                .or_insert_with(|| {
+                    json!({
+                        "type": "pulumi:providers:aws",
+                        "properties": { "region": region },
+                    })
+                });
+
+            options["provider"] = json!(format!("${{{}}}", provider));
+        }
+
+        if !resource.depends_on.is_empty() {
+            options["dependsOn"] = resource
+                .depends_on
+                .iter()
+                .map(|(resource_type, name)| {
+                    json!(format!("${{{}}}", logical_name(resource_type, name)))
+                })
+                .collect();
+        }
+
+        if !resource.ignore_changes.is_empty() {
+            options["ignoreChanges"] = resource
+                .ignore_changes
+                .iter()
+                .map(|attribute| json!(to_camel_case(attribute)))
+                .collect();
+        }
+
+        program_resources.insert(
+            logical_name(&resource.resource_type, &resource.name),
+            json!({
+                "type": token,
+                "properties": attributes_to_json(&resource.attributes),
+                "options": options,
+            }),
+        );
+    }
+
+    json!({
+        "name": PROJECT_NAME,
+        "runtime": "yaml",
+        "description": "Generated by iacgen",
+        "resources": program_resources,
+    })
+}
+
+// Pulumi logical names are unique across the whole program rather than per type, so
+// the type is folded into the name.
+fn logical_name(resource_type: &str, name: &str) -> String {
+    format!("{}-{}", name, resource_type.trim_start_matches("aws_"))
+}
+
+/// The logical name of the explicit provider for resources in another region than the
+/// stack's, e.g. `aws-eu-west-1`.
+fn provider_name(region: &str) -> String {
+    format!("aws-{}", region)
+}
+
+fn attributes_to_json(attributes: &Attributes) -> JsonValue {
+    attributes
+        .iter()
+        .map(|(key, value)| {
+            let property = match value {
+                // Repeated blocks become list properties with pluralized names, e.g.
+                // `rule` becomes `rules`.
+                Value::Blocks(_) => to_camel_case(&pluralize(key)),
+                _ => to_camel_case(key),
+            };
+
+            (property, value_to_json(value))
+        })
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::Bool(value) => json!(value),
+        Value::Number(value) => json!(value),
+        Value::String(value) => json!(escape_interpolation(value)),
+        Value::Json(document) => json!({ "fn::toJSON": escape_json(document) }),
+        Value::List(values) => values.iter().map(value_to_json).collect(),
+        // Map keys are data rather than property names, so they are not camelCased.
+        Value::Map(entries) => entries
+            .iter()
+            .map(|(key, value)| (key.clone(), json!(escape_interpolation(value))))
+            .collect::<Map<_, _>>()
+            .into(),
+        Value::Block(attributes) => attributes_to_json(attributes),
+        Value::Blocks(blocks) => blocks.iter().map(attributes_to_json).collect(),
+        Value::Reference(reference) => json!(reference_to_interpolation(reference)),
+        Value::Lookup(lookup) => json!(escape_interpolation(&lookup.value)),
+        Value::Variable(variable) => variable
+            .fallback()
+            .map(value_to_json)
+            .unwrap_or(JsonValue::Null),
+    }
+}
+
+fn reference_to_interpolation(reference: &Reference) -> String {
+    format!(
+        "${{{}.{}}}",
+        logical_name(&reference.resource_type, &reference.name),
+        to_camel_case(&reference.attribute)
+    )
+}
+
+fn escape_json(value: &JsonValue) -> JsonValue {
+    match value {
+        JsonValue::String(value) => json!(escape_interpolation(value)),
+        JsonValue::Array(values) => values.iter().map(escape_json).collect(),
+        JsonValue::Object(entries) => entries
+            .iter()
+            .map(|(key, value)| (key.clone(), escape_json(value)))
+            .collect::<Map<_, _>>()
+            .into(),
+        other => other.clone(),
+    }
+}
+
+// Literal `${` sequences, e.g. IAM policy variables, would otherwise be read as
+// Pulumi interpolations.
+fn escape_interpolation(value: &str) -> String {
+    value.replace("${", "$${")
+}
+
+fn to_camel_case(name: &str) -> String {
+    let mut output = String::with_capacity(name.len());
+    let mut capitalize = false;
+
+    for c in name.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            output.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
+
+fn pluralize(name: &str) -> String {
+    if let Some(stem) = name.strip_suffix('y')
+        && !stem.ends_with(['a', 'e', 'i', 'o', 'u'])
+    {
+        format!("{}ies", stem)
+    } else if name.ends_with('s') || name.ends_with('x') || name.ends_with("ch") {
+        format!("{}es", name)
+    } else {
+        format!("{}s", name)
+    }
+}
@@ -0,0 +1,321 @@
+use anyhow::Result;
+use hcl::{
+    Block, BlockBuilder, Body, Expression, Identifier, ObjectKey,
+    expr::{Heredoc, TemplateExpr, Traversal, Variable},
+};
+use serde_json::Map;
+
+use crate::{
+    core::{
+        Backend, Layout,
+        ir::{Attributes, Reference, Resource, Value},
+    },
+    terraform::{Import, ResourceAddress, RootModule, json, provider_reference},
+};
+
+const VERSIONS_FILE: &str = "versions";
+const PROVIDERS_FILE: &str = "providers";
+const VARIABLES_FILE: &str = "variables";
+const ROOT_MODULE_FILES: &[&str] = &[VERSIONS_FILE, PROVIDERS_FILE, VARIABLES_FILE];
+
+/// The syntax Terraform configuration is rendered in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Syntax {
+    /// Terraform native syntax (`.tf`)
+    Hcl,
+    /// Terraform JSON syntax (`.tf.json`)
+    Json,
+}
+
+/// Renders resources as Terraform configuration, optionally alongside Terraform 1.5+
+/// `import` blocks that adopt the existing infrastructure.
+pub struct ConfigBackend {
+    syntax: Syntax,
+    import_blocks: bool,
+    root_module: RootModule,
+}
+
+impl ConfigBackend {
+    pub fn new(syntax: Syntax) -> Self {
+        Self {
+            syntax,
+            import_blocks: true,
+            root_module: RootModule::default(),
+        }
+    }
+
+    pub fn with_import_blocks(mut self, import_blocks: bool) -> Self {
+        self.import_blocks = import_blocks;
+        self
+    }
+
+    pub fn with_root_module(mut self, root_module: RootModule) -> Self {
+        self.root_module = root_module;
+        self
+    }
+
+    fn to_body(&self, resources: &[Resource]) -> Body {
+        let mut body = Body::builder();
+
+        for resource in resources {
+            body = body.add_block(resource_to_block(resource));
+
+            if self.import_blocks {
+                body = body.add_block(Import::from(resource).to_block());
+            }
+        }
+
+        body.build()
+    }
+
+    fn render_body(&self, body: &Body) -> Result<String> {
+        match self.syntax {
+            Syntax::Hcl => Ok(collapse_empty_blocks(&hcl::format::to_string(body)?)),
+            Syntax::Json => {
+                let mut document = Map::new();
+                json::merge_body(&mut document, body);
+                Ok(serde_json::to_string_pretty(&document)? + "\n")
+            }
+        }
+    }
+
+    fn file_name(&self, stem: &str) -> String {
+        match self.syntax {
+            Syntax::Hcl => format!("{}.tf", stem),
+            Syntax::Json => format!("{}.tf.json", stem),
+        }
+    }
+}
+
+impl Backend for ConfigBackend {
+    fn render(&self, resources: &[Resource]) -> Result<String> {
+        let body = self
+            .root_module
+            .versions_body(self.import_blocks)
+            .into_iter()
+            .chain(self.root_module.providers_body(resources))
+            .chain(variables_body(resources))
+            .chain(self.to_body(resources))
+            .collect();
+
+        self.render_body(&body)
+    }
+
+    fn render_files(
+        &self,
+        resources: &[Resource],
+        layout: Layout,
+    ) -> Result<Vec<(String, String)>> {
+        let mut files = vec![
+            (
+                self.file_name(VERSIONS_FILE),
+                self.render_body(&self.root_module.versions_body(self.import_blocks))?,
+            ),
+            (
+                self.file_name(PROVIDERS_FILE),
+                self.render_body(&self.root_module.providers_body(resources))?,
+            ),
+        ];
+
+        if resources
+            .iter()
+            .any(|resource| !resource.variables().is_empty())
+        {
+            files.push((
+                self.file_name(VARIABLES_FILE),
+                self.render_body(&variables_body(resources))?,
+            ));
+        }
+
+        for (stem, group) in layout.partition(resources) {
+            // Keep resources from overwriting the shared root module files, e.g. for a
+            // bucket named `versions`.
+            let stem = if ROOT_MODULE_FILES.contains(&stem.as_str()) {
+                format!("{}_{}", group[0].service, stem)
+            } else {
+                stem
+            };
+
+            files.push((
+                self.file_name(&stem),
+                self.render_body(&self.to_body(&group))?,
+            ));
+        }
+
+        Ok(files)
+    }
+}
+
+// The formatter indents the body of an empty nested block as if it had contents,
+// producing e.g. `filter {    }`.
+fn collapse_empty_blocks(formatted: &str) -> String {
+    formatted
+        .lines()
+        .map(|line| match line.strip_suffix('}') {
+            Some(rest) if rest.ends_with(' ') && rest.trim_end().ends_with('{') => {
+                format!("{}}}\n", rest.trim_end())
+            }
+            _ => format!("{}\n", line),
+        })
+        .collect()
+}
+
+pub fn resource_to_block(resource: &Resource) -> Block {
+    let mut builder = Block::builder("resource")
+        .add_label(resource.resource_type.clone())
+        .add_label(resource.name.clone());
+
+    if let Some(region) = &resource.region {
+        builder = builder.add_attribute(("provider", provider_reference(region)));
+    }
+
+    let mut builder = add_attributes(builder, &resource.attributes);
+
+    if !resource.depends_on.is_empty() {
+        builder = builder.add_attribute((
+            "depends_on",
+            Expression::Array(
+                resource
+                    .depends_on
+                    .iter()
+                    .map(|(resource_type, name)| {
+                        ResourceAddress::new(resource_type, name)
+                            .to_traversal()
+                            .into()
+                    })
+                    .collect(),
+            ),
+        ));
+    }
+
+    if !resource.ignore_changes.is_empty() {
+        builder = builder.add_block(
+            Block::builder("lifecycle")
+                .add_attribute((
+                    "ignore_changes",
+                    Expression::Array(
+                        resource
+                            .ignore_changes
+                            .iter()
+                            .map(|attribute| Variable::new(attribute.clone()).unwrap().into())
+                            .collect(),
+                    ),
+                ))
+                .build(),
+        );
+    }
+
+    builder.build()
+}
+
+/// Builds a `variable` block for every input variable `resources` are supplied through.
+fn variables_body(resources: &[Resource]) -> Body {
+    let mut body = Body::builder();
+    let mut declared = Vec::new();
+
+    for variable in resources.iter().flat_map(Resource::variables) {
+        if declared.contains(&&variable.name) {
+            continue;
+        }
+        declared.push(&variable.name);
+
+        let mut block = Block::builder("variable")
+            .add_label(variable.name.clone())
+            .add_attribute(("description", variable.description.clone()));
+
+        if let Some(default) = &variable.default {
+            block = block.add_attribute(("default", value_to_expression("default", default)));
+        }
+
+        if variable.sensitive {
+            block = block.add_attribute(("sensitive", true));
+        }
+
+        body = body.add_block(block.build());
+    }
+
+    body.build()
+}
+
+fn add_attributes(mut builder: BlockBuilder, attributes: &Attributes) -> BlockBuilder {
+    for (key, value) in attributes.iter() {
+        builder = match value {
+            Value::Block(attributes) => {
+                builder.add_block(add_attributes(Block::builder(key), attributes).build())
+            }
+            Value::Blocks(blocks) => builder.add_blocks(
+                blocks
+                    .iter()
+                    .map(|attributes| add_attributes(Block::builder(key), attributes).build()),
+            ),
+            value => builder.add_attribute((key, value_to_expression(key, value))),
+        };
+    }
+
+    builder
+}
+
+fn value_to_expression(key: &str, value: &Value) -> Expression {
+    match value {
+        Value::Bool(value) => Expression::from(*value),
+        Value::Number(value) => Expression::from(*value),
+        Value::String(value) => Expression::from(value.clone()),
+        Value::Json(document) => {
+            let formatted_json = serde_json::to_string_pretty(document).unwrap();
+
+            // Documents such as policies may contain IAM policy variables like
+            // `${aws:username}`, which must not be read as interpolation sequences.
+            let template = formatted_json.replace("${", "$${").replace("%{", "%%{") + "\n";
+            let delimiter = Identifier::new(key.to_uppercase()).unwrap();
+
+            TemplateExpr::from(Heredoc::new(delimiter, template)).into()
+        }
+        Value::List(values) => Expression::Array(
+            values
+                .iter()
+                .map(|value| value_to_expression(key, value))
+                .collect(),
+        ),
+        Value::Map(entries) => Expression::Object(
+            entries
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        ObjectKey::Expression(Expression::from(key.clone())),
+                        Expression::from(value.clone()),
+                    )
+                })
+                .collect(),
+        ),
+        Value::Block(attributes) => Expression::Object(
+            attributes
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        ObjectKey::from(Identifier::new(key).unwrap()),
+                        value_to_expression(key, value),
+                    )
+                })
+                .collect(),
+        ),
+        Value::Blocks(blocks) => Expression::Array(
+            blocks
+                .iter()
+                .map(|attributes| value_to_expression(key, &Value::Block(attributes.clone())))
+                .collect(),
+        ),
+        Value::Reference(reference) => reference_to_traversal(reference).into(),
+        Value::Lookup(lookup) => Expression::from(lookup.value.clone()),
+        Value::Variable(variable) => Traversal::builder(Variable::new("var").unwrap())
+            .attr(variable.name.clone())
+            .build()
+            .into(),
+    }
+}
+
+pub fn reference_to_traversal(reference: &Reference) -> Traversal {
+    Traversal::builder(Variable::new(reference.resource_type.clone()).unwrap())
+        .attr(reference.name.clone())
+        .attr(reference.attribute.clone())
+        .build()
+}
@@ -0,0 +1,118 @@
+use anyhow::Result;
+use std::fmt;
+
+use hcl::{
+    Block,
+    expr::{Traversal, Variable},
+};
+
+use crate::{
+    core::{Backend, ir::Resource},
+    terraform::provider_reference,
+};
+
+/// The address of a resource within the generated configuration, e.g. `aws_s3_bucket.logs`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResourceAddress {
+    pub resource_type: String,
+    pub name: String,
+}
+
+impl ResourceAddress {
+    pub fn new(resource_type: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            resource_type: resource_type.into(),
+            name: name.into(),
+        }
+    }
+
+    pub fn to_traversal(&self) -> Traversal {
+        Traversal::builder(Variable::new(self.resource_type.clone()).unwrap())
+            .attr(self.name.clone())
+            .build()
+    }
+}
+
+impl From<&Resource> for ResourceAddress {
+    fn from(value: &Resource) -> Self {
+        Self::new(value.resource_type.clone(), value.name.clone())
+    }
+}
+
+impl fmt::Display for ResourceAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.resource_type, self.name)
+    }
+}
+
+/// An existing resource that Terraform should adopt into state at `to` using the
+/// provider-specific import `id`.
+pub struct Import {
+    pub to: ResourceAddress,
+    pub id: String,
+    /// The region of the aliased provider the resource is imported with, if any.
+    pub region: Option<String>,
+}
+
+impl Import {
+    pub fn new(to: ResourceAddress, id: impl Into<String>) -> Self {
+        Self {
+            to,
+            id: id.into(),
+            region: None,
+        }
+    }
+
+    pub fn with_region(mut self, region: Option<String>) -> Self {
+        self.region = region;
+        self
+    }
+
+    pub fn to_block(&self) -> Block {
+        let mut builder = Block::builder("import")
+            .add_attribute(("to", self.to.to_traversal()))
+            .add_attribute(("id", self.id.clone()));
+
+        if let Some(region) = &self.region {
+            builder = builder.add_attribute(("provider", provider_reference(region)));
+        }
+
+        builder.build()
+    }
+
+    /// Renders the import as a `terraform import` command for Terraform versions that
+    /// predate `import` blocks.
+    pub fn to_command(&self) -> String {
+        format!(
+            "terraform import {} {}",
+            shell_quote(&self.to.to_string()),
+            shell_quote(&self.id)
+        )
+    }
+}
+
+impl From<&Resource> for Import {
+    fn from(value: &Resource) -> Self {
+        Self::new(ResourceAddress::from(value), value.import_id.clone())
+            .with_region(value.region.clone())
+    }
+}
+
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+/// Renders an executable script of `terraform import` commands, for Terraform versions
+/// that predate `import` blocks.
+pub struct ImportScriptBackend;
+
+impl Backend for ImportScriptBackend {
+    fn render(&self, resources: &[Resource]) -> Result<String> {
+        // Fetchers list parent resources before their sub-resources, e.g. buckets before
+        // their policies, so the commands adopt them in that order too.
+        Ok(resources
+            .iter()
+            .map(|resource| Import::from(resource).to_command() + "\n")
+            .collect())
+    }
+}
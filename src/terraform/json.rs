@@ -0,0 +1,107 @@
+use hcl::{Block, Body, Expression, Structure, expr::TemplateExpr};
+use serde_json::{Map, Value};
+
+/// Meta-arguments whose values are static references rather than expressions. Terraform
+/// parses these from plain JSON strings without `${}` interpolation.
+const STATIC_REFERENCE_ATTRIBUTES: &[&str] = &["to", "depends_on", "provider", "ignore_changes"];
+
+/// Merges an HCL body into a document using Terraform's JSON configuration syntax.
+///
+/// Labeled blocks nest their body under each label in turn, so resource blocks from
+/// several bodies merge into a single `resource` object. Blocks that repeat with the
+/// same labels within the same object, such as aliased `provider "aws"` blocks, are
+/// collected into an array.
+pub fn merge_body(document: &mut Map<String, Value>, body: &Body) {
+    for structure in body.iter() {
+        match structure {
+            Structure::Attribute(attribute) => {
+                let value = if STATIC_REFERENCE_ATTRIBUTES.contains(&attribute.key()) {
+                    static_reference_to_json(&attribute.expr)
+                } else {
+                    expression_to_json(&attribute.expr)
+                };
+
+                document.insert(attribute.key().to_string(), value);
+            }
+            Structure::Block(block) => merge_block(document, block),
+        }
+    }
+}
+
+fn merge_block(document: &mut Map<String, Value>, block: &Block) {
+    let mut block_body = Map::new();
+    merge_body(&mut block_body, &block.body);
+    let value = Value::Object(block_body);
+
+    let mut target = document;
+    let mut key = block.identifier().to_string();
+
+    for label in block.labels.iter() {
+        // Blocks of the same type always have the same number of labels, so the objects
+        // leading up to the innermost label are never collected into arrays.
+        let Value::Object(labeled) = target
+            .entry(key)
+            .or_insert_with(|| Value::Object(Map::new()))
+        else {
+            unreachable!("labeled blocks nest within objects");
+        };
+
+        target = labeled;
+        key = label.as_str().to_string();
+    }
+
+    match target.get_mut(&key) {
+        None => {
+            target.insert(key, value);
+        }
+        Some(Value::Array(existing)) => existing.push(value),
+        Some(existing) => {
+            let first = existing.take();
+            *existing = Value::Array(vec![first, value]);
+        }
+    }
+}
+
+fn expression_to_json(expr: &Expression) -> Value {
+    match expr {
+        Expression::Null => Value::Null,
+        Expression::Bool(value) => Value::Bool(*value),
+        Expression::Number(value) => serde_json::to_value(value).unwrap_or(Value::Null),
+        Expression::String(value) => Value::String(escape_template(value)),
+        Expression::Array(values) => Value::Array(values.iter().map(expression_to_json).collect()),
+        Expression::Object(object) => Value::Object(
+            object
+                .iter()
+                .map(|(key, value)| (key.to_string(), expression_to_json(value)))
+                .collect(),
+        ),
+        Expression::TemplateExpr(template) => Value::String(template_to_json(template)),
+        // Anything else, e.g. a traversal, is a native expression that has to be
+        // wrapped in an interpolation sequence.
+        other => Value::String(format!("${{{}}}", other)),
+    }
+}
+
+fn static_reference_to_json(expr: &Expression) -> Value {
+    match expr {
+        Expression::Array(values) => {
+            Value::Array(values.iter().map(static_reference_to_json).collect())
+        }
+        other => Value::String(other.to_string()),
+    }
+}
+
+fn template_to_json(template: &TemplateExpr) -> String {
+    match template {
+        TemplateExpr::QuotedString(value) => value.clone(),
+        // Heredoc templates always end with a newline before the closing delimiter, which
+        // is not part of the value in JSON syntax.
+        TemplateExpr::Heredoc(heredoc) => heredoc.template.trim_end_matches('\n').to_string(),
+    }
+}
+
+// Strings in Terraform's JSON syntax are interpreted as templates, so literal
+// interpolation sequences have to be escaped.
+fn escape_template(value: &str) -> String {
+    value.replace("${", "$${").replace("%{", "%%{")
+}
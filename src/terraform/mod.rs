@@ -1,12 +1,31 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-pub mod s3;
+pub mod config;
+pub mod import;
+pub mod json;
+pub mod root_module;
+pub mod state;
 
-static RESOURCE_NAME_REPLACE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"[-. /]").unwrap());
+pub use config::{ConfigBackend, Syntax};
+pub use import::{Import, ImportScriptBackend, ResourceAddress};
+pub use root_module::{
+    DEFAULT_AWS_PROVIDER_VERSION, RootModule, TerraformBackend, provider_reference,
+};
+pub use state::StateBackend;
 
+static RESOURCE_NAME_REPLACE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"[^A-Za-z0-9_]").unwrap());
+
+/// Converts a name into a valid Terraform resource name, e.g. a `Name` tag of
+/// `10.0.0.0/16 (prod)` becomes `_10_0_0_0_16__prod_`.
 pub fn normalize_resource_name(name: &str) -> String {
-    RESOURCE_NAME_REPLACE_REGEX
-        .replace_all(name, "_")
-        .to_string()
+    let name = RESOURCE_NAME_REPLACE_REGEX.replace_all(name, "_");
+
+    // Resource names must start with a letter or underscore.
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name.to_string()
+    }
 }
@@ -0,0 +1,176 @@
+use std::collections::BTreeSet;
+
+use hcl::{
+    Block, Body, Expression, Identifier, Object, ObjectKey,
+    expr::{Traversal, Variable},
+};
+
+use crate::core::ir::Resource;
+
+const AWS_PROVIDER_SOURCE: &str = "hashicorp/aws";
+
+/// The AWS provider version constraint used unless one is configured.
+pub const DEFAULT_AWS_PROVIDER_VERSION: &str = "~> 6.0";
+
+/// `import` blocks were introduced in Terraform 1.5.
+const IMPORT_BLOCKS_REQUIRED_VERSION: &str = ">= 1.5.0";
+
+/// Where Terraform stores state for the generated root module.
+pub enum TerraformBackend {
+    S3 {
+        bucket: String,
+        key: String,
+        region: Option<String>,
+    },
+    Local {
+        path: Option<String>,
+    },
+}
+
+/// The settings that make generated configuration a runnable root module: provider
+/// requirements, provider configuration and an optional state backend.
+pub struct RootModule {
+    provider_version: String,
+    region: Option<String>,
+    profile: Option<String>,
+    backend: Option<TerraformBackend>,
+}
+
+impl Default for RootModule {
+    fn default() -> Self {
+        Self::new(DEFAULT_AWS_PROVIDER_VERSION)
+    }
+}
+
+impl RootModule {
+    pub fn new(provider_version: impl Into<String>) -> Self {
+        Self {
+            provider_version: provider_version.into(),
+            region: None,
+            profile: None,
+            backend: None,
+        }
+    }
+
+    pub fn with_region(mut self, region: Option<String>) -> Self {
+        self.region = region;
+        self
+    }
+
+    pub fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_backend(mut self, backend: TerraformBackend) -> Self {
+        self.backend = Some(backend);
+        self
+    }
+
+    /// Builds the `terraform` block declaring the required Terraform and provider
+    /// versions, along with the state backend if one is configured.
+    pub fn versions_body(&self, import_blocks: bool) -> Body {
+        let aws_provider = Object::from([
+            (
+                identifier_key("source"),
+                Expression::from(AWS_PROVIDER_SOURCE),
+            ),
+            (
+                identifier_key("version"),
+                Expression::from(self.provider_version.clone()),
+            ),
+        ]);
+
+        let mut terraform = Block::builder("terraform");
+
+        if import_blocks {
+            terraform =
+                terraform.add_attribute(("required_version", IMPORT_BLOCKS_REQUIRED_VERSION));
+        }
+
+        terraform = terraform.add_block(
+            Block::builder("required_providers")
+                .add_attribute(("aws", aws_provider))
+                .build(),
+        );
+
+        match &self.backend {
+            Some(TerraformBackend::S3 {
+                bucket,
+                key,
+                region,
+            }) => {
+                let mut backend = Block::builder("backend")
+                    .add_label("s3")
+                    .add_attribute(("bucket", bucket.clone()))
+                    .add_attribute(("key", key.clone()));
+
+                if let Some(region) = region.as_ref().or(self.region.as_ref()) {
+                    backend = backend.add_attribute(("region", region.clone()));
+                }
+
+                terraform = terraform.add_block(backend.build());
+            }
+            Some(TerraformBackend::Local { path }) => {
+                let mut backend = Block::builder("backend").add_label("local");
+
+                if let Some(path) = path {
+                    backend = backend.add_attribute(("path", path.clone()));
+                }
+
+                terraform = terraform.add_block(backend.build());
+            }
+            None => {}
+        }
+
+        Body::builder().add_block(terraform.build()).build()
+    }
+
+    /// Builds the `provider` block configuring the AWS provider with the region and
+    /// profile resources were fetched with, plus an aliased block for every other region
+    /// `resources` live in.
+    pub fn providers_body(&self, resources: &[Resource]) -> Body {
+        let regions: BTreeSet<_> = resources
+            .iter()
+            .filter_map(|resource| resource.region.as_deref())
+            .collect();
+
+        let mut body = Body::builder().add_block(self.provider_block(None, self.region.as_deref()));
+
+        for region in regions {
+            body = body.add_block(self.provider_block(Some(region), Some(region)));
+        }
+
+        body.build()
+    }
+
+    fn provider_block(&self, alias: Option<&str>, region: Option<&str>) -> Block {
+        let mut provider = Block::builder("provider").add_label("aws");
+
+        if let Some(alias) = alias {
+            provider = provider.add_attribute(("alias", alias));
+        }
+
+        if let Some(region) = region {
+            provider = provider.add_attribute(("region", region));
+        }
+
+        if let Some(profile) = &self.profile {
+            provider = provider.add_attribute(("profile", profile.clone()));
+        }
+
+        provider.build()
+    }
+}
+
+/// A reference to the aliased provider configuration for `region`, e.g.
+/// `aws.eu-west-1`.
+pub fn provider_reference(region: &str) -> Traversal {
+    Traversal::builder(Variable::new("aws").unwrap())
+        .attr(Identifier::new(region).unwrap())
+        .build()
+}
+
+fn identifier_key(key: &str) -> ObjectKey {
+    ObjectKey::from(Identifier::new(key).unwrap())
+}